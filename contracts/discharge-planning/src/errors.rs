@@ -0,0 +1,35 @@
+use soroban_sdk::contracterror;
+
+/// Error codes returned by the discharge-planning contract. Variants are
+/// appended as new validation rules are introduced; existing codes are
+/// never renumbered once shipped, since clients match on the numeric value.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    NotAuthorized = 1,
+    PlanNotFound = 2,
+    InvalidScore = 3,
+    InvalidInput = 4,
+    AlreadyCompleted = 5,
+    TooManyAppointments = 6,
+    UnknownPreset = 7,
+    NotYetAdmitted = 8,
+    MissingSummary = 9,
+    TooManyOccurrences = 10,
+    CounterExhausted = 11,
+    NoFollowupScheduled = 12,
+    InvalidDate = 13,
+    ConsentMissing = 14,
+    CosignRequired = 15,
+    NotReadmitted = 16,
+    CoordinationExists = 17,
+    OpenBarriersRemain = 18,
+    AlreadyDelivered = 19,
+    InsufficientHistory = 20,
+    MandatoryOrdersMissing = 21,
+    InvalidStateTransition = 22,
+    InvalidWeights = 23,
+    NotCompleted = 24,
+    AuthorizationRequired = 25,
+}
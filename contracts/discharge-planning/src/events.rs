@@ -0,0 +1,15 @@
+use soroban_sdk::{symbol_short, Symbol};
+
+/// Canonical event topics, so off-chain consumers and tests can match on a
+/// single exported constant instead of re-typing the `symbol_short!` string.
+pub const TOPIC_INIT: Symbol = symbol_short!("init");
+pub const TOPIC_READY: Symbol = symbol_short!("ready");
+pub const TOPIC_ORDER: Symbol = symbol_short!("order");
+pub const TOPIC_COMPLETE: Symbol = symbol_short!("complete");
+pub const TOPIC_ESCALATE: Symbol = symbol_short!("escalate");
+pub const TOPIC_EMERGENCY_ACCESS: Symbol = symbol_short!("emerg_acc");
+pub const TOPIC_REASSIGN: Symbol = symbol_short!("reassign");
+pub const TOPIC_OVERRIDE: Symbol = symbol_short!("override");
+pub const TOPIC_CANCEL: Symbol = symbol_short!("cancel");
+pub const TOPIC_AMEND: Symbol = symbol_short!("amend");
+pub const TOPIC_STAGE_CHANGED: Symbol = symbol_short!("stage_chg");
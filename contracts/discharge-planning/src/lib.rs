@@ -0,0 +1,3064 @@
+#![no_std]
+
+mod errors;
+mod events;
+mod storage;
+mod types;
+
+#[cfg(test)]
+mod test;
+
+use soroban_sdk::{
+    contract, contractimpl, symbol_short,
+    xdr::{FromXdr, ToXdr},
+    Address, Bytes, BytesN, Env, IntoVal, Symbol, Vec,
+};
+
+pub use errors::Error;
+pub use events::*;
+pub use types::*;
+
+#[contract]
+pub struct DischargePlanningContract;
+
+/// `initiate_discharge_planning` permits a future `admission_date` for
+/// scheduled admissions, but clinical actions (orders, readiness,
+/// education) assume the patient has actually been admitted.
+fn require_admitted(env: &Env, plan: &DischargePlan) -> Result<(), Error> {
+    if env.ledger().timestamp() < plan.admission_date {
+        return Err(Error::NotYetAdmitted);
+    }
+    Ok(())
+}
+
+/// Resolves the readiness threshold that actually applies to `plan`, most
+/// specific override wins: a per-plan override (`set_readiness_threshold_for_plan`),
+/// then a per-destination override (`set_readiness_threshold_by_dest`),
+/// then a contract-wide override (`set_global_readiness_threshold`), falling
+/// back to `DEFAULT_READINESS_THRESHOLD`.
+fn effective_readiness_threshold(env: &Env, plan: &DischargePlan) -> u32 {
+    storage::plan_readiness_threshold(env, plan.id)
+        .or_else(|| storage::readiness_threshold(env, plan.destination))
+        .or_else(|| storage::global_readiness_threshold(env))
+        .unwrap_or(DEFAULT_READINESS_THRESHOLD)
+}
+
+/// Cross-calls `notify(plan_id, event_code)` on every address registered
+/// via `subscribe_plan_events`. The subscriber list is capped at
+/// `MAX_SUBSCRIBERS_PER_PLAN`, so this fan-out stays bounded; a misbehaving
+/// subscriber contract aborts the whole triggering transaction, so callers
+/// should only subscribe contracts they trust.
+fn notify_subscribers(env: &Env, plan_id: u64, event_code: u32) {
+    let subscribers = storage::plan_subscribers(env, plan_id);
+    let notify = Symbol::new(env, "notify");
+    for subscriber in subscribers.iter() {
+        let args: Vec<soroban_sdk::Val> = soroban_sdk::vec![
+            env,
+            plan_id.into_val(env),
+            event_code.into_val(env),
+        ];
+        env.invoke_contract::<()>(&subscriber, &notify, args);
+    }
+}
+
+/// Canonical "stage changed" event, fired alongside every `DischargePlan`
+/// lifecycle-stage mutation so off-chain indexers can track state
+/// transitions from one topic instead of correlating many topic-specific
+/// events (`TOPIC_READY`, `TOPIC_ORDER`, `TOPIC_COMPLETE`, ...).
+fn emit_stage_changed(env: &Env, plan_id: u64, from_stage: u32, to_stage: u32, caller: Address) {
+    env.events()
+        .publish((TOPIC_STAGE_CHANGED, caller), (plan_id, from_stage, to_stage));
+}
+
+/// The six gates `can_complete_discharge` / `get_discharge_blockers`
+/// check, in the order their blocker codes are assigned (1..6).
+struct DischargeGates {
+    ready_and_fresh: bool,
+    education_complete: bool,
+    consent_present: bool,
+    no_open_barriers: bool,
+    dme_delivered: bool,
+    pcp_followup_compliant: bool,
+}
+
+impl DischargeGates {
+    fn all_pass(&self) -> bool {
+        self.ready_and_fresh
+            && self.education_complete
+            && self.consent_present
+            && self.no_open_barriers
+            && self.dme_delivered
+            && self.pcp_followup_compliant
+    }
+}
+
+/// Evaluates every configured discharge gate for `plan` as of `as_of`:
+/// a physician's `override_readiness` call wins outright; otherwise the
+/// latest readiness assessment must be ready and within
+/// `READINESS_FRESHNESS_WINDOW_SECS`; every topic in
+/// `set_required_education_topics` must be completed; consent must be
+/// recorded if `require_patient_consent`; no barrier may be open if
+/// `require_barriers_resolved`; any DME order placed must have a
+/// `status` (set by `finalize_open_orders`); and, if
+/// `require_pcp_followup`, a primary-care appointment must fall within the
+/// PCP follow-up window after `expected_discharge_date`.
+fn evaluate_discharge_gates(env: &Env, plan: &DischargePlan, as_of: u64) -> DischargeGates {
+    let ready_and_fresh = match storage::readiness_override(env, plan.id) {
+        Some(over) => over.ready,
+        None => storage::readiness_history(env, plan.id)
+            .last()
+            .map(|latest| {
+                latest.is_ready
+                    && as_of.saturating_sub(latest.assessed_at) <= READINESS_FRESHNESS_WINDOW_SECS
+            })
+            .unwrap_or(false),
+    };
+
+    let required_topics = storage::required_education_topics(env, plan.id);
+    let records = storage::education_records(env, plan.id);
+    let education_complete = required_topics
+        .iter()
+        .all(|topic| records.iter().any(|r| r.topic == topic && r.completed));
+
+    let consent_present = !plan.require_patient_consent
+        || storage::consent_record(env, plan.id)
+            .map(|record| record.consented)
+            .unwrap_or(false);
+
+    let no_open_barriers = !plan.require_barriers_resolved
+        || !storage::barriers(env, plan.id).iter().any(|barrier| !barrier.resolved);
+
+    let dme_delivered = !storage::orders(env, plan.id)
+        .iter()
+        .any(|order| order.order_type == ORDER_TYPE_DME && order.status.is_none());
+
+    let pcp_followup_compliant = !plan.require_pcp_followup || {
+        let window_secs = storage::pcp_followup_window_days(env) as u64 * 86_400;
+        let deadline = plan.expected_discharge_date.saturating_add(window_secs);
+        storage::appointments(env, plan.id).iter().any(|appointment| {
+            appointment.specialty == 0
+                && appointment.time >= plan.expected_discharge_date
+                && appointment.time <= deadline
+        })
+    };
+
+    DischargeGates {
+        ready_and_fresh,
+        education_complete,
+        consent_present,
+        no_open_barriers,
+        dme_delivered,
+        pcp_followup_compliant,
+    }
+}
+
+/// Slices `[start, start + limit)` out of an id list for worklist-style
+/// pagination, clamping to the list's actual length.
+fn paginate(env: &Env, ids: &Vec<u64>, start: u32, limit: u32) -> Vec<u64> {
+    let mut page = Vec::new(env);
+    for i in start..ids.len().min(start.saturating_add(limit)) {
+        page.push_back(ids.get(i).unwrap());
+    }
+    page
+}
+
+/// Keeps the oldest `FULL_EXPORT_MAX_ITEMS` entries of a history collection,
+/// used by `get_full_plan_export` to bound its response size.
+fn truncate<T>(env: &Env, list: &Vec<T>) -> Vec<T>
+where
+    T: soroban_sdk::TryFromVal<Env, soroban_sdk::Val> + soroban_sdk::IntoVal<Env, soroban_sdk::Val>,
+{
+    let mut truncated = Vec::new(env);
+    for i in 0..list.len().min(FULL_EXPORT_MAX_ITEMS) {
+        truncated.push_back(list.get(i).unwrap());
+    }
+    truncated
+}
+
+#[contractimpl]
+impl DischargePlanningContract {
+    pub fn initiate_discharge_planning(
+        env: Env,
+        caller: Address,
+        patient_id: u64,
+        attending_provider_id: u64,
+        admission_date: u64,
+        expected_discharge_date: u64,
+        destination: Destination,
+    ) -> Result<u64, Error> {
+        caller.require_auth();
+        if patient_id == 0 {
+            return Err(Error::InvalidInput);
+        }
+
+        let id = storage::get_and_increment_plan_counter(&env)?;
+        let plan = DischargePlan {
+            id,
+            patient_id,
+            attending_provider_id,
+            admission_date,
+            expected_discharge_date,
+            actual_discharge_date: 0,
+            destination,
+            status: STAGE_INITIATED,
+            created_at: env.ledger().timestamp(),
+            language_code: 0,
+            escalated: false,
+            readmitted: false,
+            drg_code: None,
+            require_followup_before_dc: false,
+            require_patient_consent: false,
+            require_barriers_resolved: false,
+            pathway_id: None,
+            last_updated: env.ledger().timestamp(),
+            lace_index: None,
+            require_pcp_followup: false,
+            require_authorization: false,
+            diagnosis_code: None,
+        };
+        storage::set_plan(&env, &plan);
+        storage::push_event_log(&env, id, EVENT_INITIATED);
+        storage::add_to_destination_index(&env, destination, id);
+        storage::add_to_provider_index(&env, attending_provider_id, id);
+        notify_subscribers(&env, id, EVENT_INITIATED);
+
+        env.events()
+            .publish((TOPIC_INIT, caller), id);
+
+        Ok(id)
+    }
+
+    pub fn get_discharge_plan(env: Env, discharge_plan_id: u64) -> Result<DischargePlan, Error> {
+        storage::get_plan(&env, discharge_plan_id)
+    }
+
+    pub fn change_discharge_destination(
+        env: Env,
+        caller: Address,
+        discharge_plan_id: u64,
+        new_destination: Destination,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        let mut plan = storage::get_plan(&env, discharge_plan_id)?;
+
+        storage::remove_from_destination_index(&env, plan.destination, discharge_plan_id);
+        storage::add_to_destination_index(&env, new_destination, discharge_plan_id);
+
+        plan.destination = new_destination;
+        storage::set_plan(&env, &plan);
+        Ok(())
+    }
+
+    /// Length-of-stay-in-progress helper: days between `admission_date` and
+    /// `as_of`, clamped to 0 if `as_of` precedes admission.
+    pub fn get_days_since_admission(
+        env: Env,
+        discharge_plan_id: u64,
+        as_of: u64,
+    ) -> Result<u64, Error> {
+        let plan = storage::get_plan(&env, discharge_plan_id)?;
+        Ok(as_of.saturating_sub(plan.admission_date) / 86_400)
+    }
+
+    /// Timestamp of the last write to the plan record itself, for worklist
+    /// "last touched" sorting and stale-plan detection.
+    pub fn get_last_updated(env: Env, discharge_plan_id: u64) -> Result<u64, Error> {
+        Ok(storage::get_plan(&env, discharge_plan_id)?.last_updated)
+    }
+
+    /// Post-acute network managers want all plans bound for a given
+    /// destination (e.g. SNF); the index is maintained on initiation and
+    /// by `change_discharge_destination`.
+    pub fn get_plans_by_destination(
+        env: Env,
+        destination: Destination,
+        start: u32,
+        limit: u32,
+    ) -> Vec<u64> {
+        let ids = storage::destination_index(&env, destination);
+        paginate(&env, &ids, start, limit)
+    }
+
+    /// Reassigns a plan's attending provider (e.g. a hospitalist handoff),
+    /// maintaining the provider index used by `get_plans_by_provider`.
+    pub fn reassign_attending_provider(
+        env: Env,
+        caller: Address,
+        discharge_plan_id: u64,
+        new_attending_provider_id: u64,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        let mut plan = storage::get_plan(&env, discharge_plan_id)?;
+
+        storage::remove_from_provider_index(&env, plan.attending_provider_id, discharge_plan_id);
+        storage::add_to_provider_index(&env, new_attending_provider_id, discharge_plan_id);
+
+        plan.attending_provider_id = new_attending_provider_id;
+        storage::set_plan(&env, &plan);
+        Ok(())
+    }
+
+    /// Hospitalists' own panel of plans, backed by the index maintained on
+    /// `initiate_discharge_planning` and `reassign_attending_provider`.
+    pub fn get_plans_by_provider(
+        env: Env,
+        provider_id: u64,
+        start: u32,
+        limit: u32,
+    ) -> Vec<u64> {
+        let ids = storage::provider_index(&env, provider_id);
+        paginate(&env, &ids, start, limit)
+    }
+
+    /// Archives (permanently removes) plans among `ids` that are still
+    /// `STAGE_INITIATED` (no readiness assessed, no orders placed) and
+    /// older than `older_than_secs`, admin-only. Never touches a plan with
+    /// any clinical activity (education, follow-up appointments, vitals
+    /// checks, risk records or a home-health arrangement) or an unknown id.
+    /// Returns the count archived.
+    pub fn purge_stale_drafts(
+        env: Env,
+        admin: Address,
+        older_than_secs: u64,
+        ids: Vec<u64>,
+    ) -> Result<u32, Error> {
+        storage::require_admin(&env, &admin)?;
+
+        let now = env.ledger().timestamp();
+        let mut archived = 0u32;
+        for plan_id in ids.iter() {
+            let Ok(plan) = storage::get_plan(&env, plan_id) else {
+                continue;
+            };
+            if plan.status != STAGE_INITIATED {
+                continue;
+            }
+            let has_activity = !storage::education_records(&env, plan_id).is_empty()
+                || !storage::appointments(&env, plan_id).is_empty()
+                || !storage::vitals_checks(&env, plan_id).is_empty()
+                || !storage::risk_history(&env, plan_id).is_empty()
+                || storage::home_health_arrangement(&env, plan_id).is_some();
+            if has_activity {
+                continue;
+            }
+            if now.saturating_sub(plan.created_at) < older_than_secs {
+                continue;
+            }
+
+            storage::remove_from_destination_index(&env, plan.destination, plan_id);
+            storage::remove_from_provider_index(&env, plan.attending_provider_id, plan_id);
+            storage::remove_plan(&env, plan_id);
+            archived += 1;
+        }
+
+        Ok(archived)
+    }
+
+    /// Records the patient's preferred language for discharge education so
+    /// `provide_discharge_education` can note whether delivered materials
+    /// matched it.
+    pub fn set_patient_language(
+        env: Env,
+        caller: Address,
+        discharge_plan_id: u64,
+        language_code: u32,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        let mut plan = storage::get_plan(&env, discharge_plan_id)?;
+        plan.language_code = language_code;
+        storage::set_plan(&env, &plan);
+        Ok(())
+    }
+
+    pub fn provide_discharge_education(
+        env: Env,
+        caller: Address,
+        discharge_plan_id: u64,
+        topic: u32,
+        completed: bool,
+        material_language_code: u32,
+        interpreter_used: bool,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        let plan = storage::get_plan(&env, discharge_plan_id)?;
+        require_admitted(&env, &plan)?;
+
+        storage::push_education_record(
+            &env,
+            discharge_plan_id,
+            EducationRecord {
+                topic,
+                provided_at: env.ledger().timestamp(),
+                completed,
+                language_matched: material_language_code == plan.language_code,
+                interpreter_used,
+            },
+        );
+        Ok(())
+    }
+
+    /// Sets the billing DRG code on a plan and indexes it for
+    /// `get_plans_by_drg`.
+    pub fn set_drg_code(
+        env: Env,
+        caller: Address,
+        discharge_plan_id: u64,
+        drg_code: u32,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        let mut plan = storage::get_plan(&env, discharge_plan_id)?;
+        plan.drg_code = Some(drg_code);
+        storage::set_plan(&env, &plan);
+        storage::add_to_drg_index(&env, drg_code, discharge_plan_id);
+        Ok(())
+    }
+
+    pub fn get_plans_by_drg(env: Env, drg_code: u32, start: u32, limit: u32) -> Vec<u64> {
+        let ids = storage::drg_index(&env, drg_code);
+        paginate(&env, &ids, start, limit)
+    }
+
+    /// Sets the principal diagnosis (ICD code) on a plan and indexes it for
+    /// `get_plans_by_diagnosis`. Rejected once the plan has completed, since
+    /// the diagnosis feeds pathway selection earlier in the workflow.
+    pub fn set_diagnosis_code(
+        env: Env,
+        caller: Address,
+        discharge_plan_id: u64,
+        icd_code: u32,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        let mut plan = storage::get_plan(&env, discharge_plan_id)?;
+        if plan.status == STAGE_COMPLETED {
+            return Err(Error::AlreadyCompleted);
+        }
+
+        plan.diagnosis_code = Some(icd_code);
+        storage::set_plan(&env, &plan);
+        storage::add_to_diagnosis_index(&env, icd_code, discharge_plan_id);
+        Ok(())
+    }
+
+    pub fn get_plans_by_diagnosis(env: Env, icd_code: u32, start: u32, limit: u32) -> Vec<u64> {
+        let ids = storage::diagnosis_index(&env, icd_code);
+        paginate(&env, &ids, start, limit)
+    }
+
+    /// Sets the order types that must exist (any status) before a plan
+    /// bound for `destination` can complete (e.g. home-health orders for
+    /// home discharges, a medical summary for SNF transfers). Checked by
+    /// `complete_discharge`, which fails with `Error::MandatoryOrdersMissing`
+    /// otherwise.
+    pub fn set_mandatory_orders(
+        env: Env,
+        admin: Address,
+        destination: u32,
+        required_types: Vec<u32>,
+    ) -> Result<(), Error> {
+        storage::require_admin(&env, &admin)?;
+        storage::set_mandatory_orders(&env, destination, &required_types);
+        Ok(())
+    }
+
+    /// Tags a plan with a care-pathway identifier (e.g. a standardized
+    /// order-set template for a given diagnosis) and indexes it for
+    /// `get_plans_by_pathway`.
+    pub fn set_care_pathway(
+        env: Env,
+        caller: Address,
+        discharge_plan_id: u64,
+        pathway_id: u32,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        let mut plan = storage::get_plan(&env, discharge_plan_id)?;
+        plan.pathway_id = Some(pathway_id);
+        storage::set_plan(&env, &plan);
+        storage::add_to_pathway_index(&env, pathway_id, discharge_plan_id);
+        Ok(())
+    }
+
+    pub fn get_plans_by_pathway(env: Env, pathway_id: u32, start: u32, limit: u32) -> Vec<u64> {
+        let ids = storage::pathway_index(&env, pathway_id);
+        paginate(&env, &ids, start, limit)
+    }
+
+    /// Opts a plan into requiring at least one scheduled follow-up
+    /// appointment before `complete_discharge` will succeed.
+    pub fn set_require_followup_before_dc(
+        env: Env,
+        caller: Address,
+        discharge_plan_id: u64,
+        required: bool,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        let mut plan = storage::get_plan(&env, discharge_plan_id)?;
+        plan.require_followup_before_dc = required;
+        storage::set_plan(&env, &plan);
+        Ok(())
+    }
+
+    pub fn get_education_records(env: Env, discharge_plan_id: u64) -> EducationRecords {
+        storage::education_records(&env, discharge_plan_id)
+    }
+
+    /// Distinct topic codes that have any education record at all
+    /// (complete or not), for auditors reviewing the full teaching record
+    /// rather than just outstanding requirements.
+    pub fn get_provided_education_topics(
+        env: Env,
+        discharge_plan_id: u64,
+    ) -> Result<Vec<u32>, Error> {
+        storage::get_plan(&env, discharge_plan_id)?;
+        let records = storage::education_records(&env, discharge_plan_id);
+
+        let mut topics = Vec::new(&env);
+        for record in records.iter() {
+            if !topics.iter().any(|topic| topic == record.topic) {
+                topics.push_back(record.topic);
+            }
+        }
+        Ok(topics)
+    }
+
+    /// Worklist of required education topics that don't yet have a
+    /// `completed` record, for coordinators closing out teaching before
+    /// discharge.
+    pub fn get_incomplete_education_topics(
+        env: Env,
+        discharge_plan_id: u64,
+        required: Vec<u32>,
+    ) -> Result<Vec<u32>, Error> {
+        storage::get_plan(&env, discharge_plan_id)?;
+        let records = storage::education_records(&env, discharge_plan_id);
+
+        let mut incomplete = Vec::new(&env);
+        for topic in required.iter() {
+            let done = records.iter().any(|r| r.topic == topic && r.completed);
+            if !done {
+                incomplete.push_back(topic);
+            }
+        }
+        Ok(incomplete)
+    }
+
+    /// Sets the list of education topics `can_complete_discharge` and
+    /// `get_discharge_blockers` require to be completed before discharge.
+    pub fn set_required_education_topics(
+        env: Env,
+        caller: Address,
+        discharge_plan_id: u64,
+        topics: Vec<u32>,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        storage::get_plan(&env, discharge_plan_id)?;
+        storage::set_required_education_topics(&env, discharge_plan_id, &topics);
+        Ok(())
+    }
+
+    /// One boolean a client can check before showing a "Discharge" button:
+    /// true only if every gate in `evaluate_discharge_gates` passes as of
+    /// `as_of`. See `get_discharge_blockers` for which gate(s) are failing.
+    pub fn can_complete_discharge(
+        env: Env,
+        discharge_plan_id: u64,
+        as_of: u64,
+    ) -> Result<bool, Error> {
+        let plan = storage::get_plan(&env, discharge_plan_id)?;
+        Ok(evaluate_discharge_gates(&env, &plan, as_of).all_pass())
+    }
+
+    /// Reason-coded complement to `can_complete_discharge`: one code per
+    /// failing gate, so a UI can list exactly what remains.
+    /// `1`=NotReady, `2`=EducationIncomplete, `3`=ConsentMissing,
+    /// `4`=OpenBarriers, `5`=DmePending, `6`=PcpFollowupMissing.
+    pub fn get_discharge_blockers(
+        env: Env,
+        discharge_plan_id: u64,
+        as_of: u64,
+    ) -> Result<Vec<u32>, Error> {
+        let plan = storage::get_plan(&env, discharge_plan_id)?;
+        let gates = evaluate_discharge_gates(&env, &plan, as_of);
+
+        let mut blockers = Vec::new(&env);
+        if !gates.ready_and_fresh {
+            blockers.push_back(1);
+        }
+        if !gates.education_complete {
+            blockers.push_back(2);
+        }
+        if !gates.consent_present {
+            blockers.push_back(3);
+        }
+        if !gates.no_open_barriers {
+            blockers.push_back(4);
+        }
+        if !gates.dme_delivered {
+            blockers.push_back(5);
+        }
+        if !gates.pcp_followup_compliant {
+            blockers.push_back(6);
+        }
+        Ok(blockers)
+    }
+
+    pub fn set_max_appointments_per_plan(env: Env, admin: Address, max: u32) -> Result<(), Error> {
+        storage::require_admin(&env, &admin)?;
+        storage::set_max_appointments_per_plan(&env, max);
+        Ok(())
+    }
+
+    /// Opts a plan into requiring a primary-care follow-up appointment
+    /// within `set_pcp_followup_window_days` of `expected_discharge_date`
+    /// before `can_complete_discharge` passes (CMS transitional-care
+    /// rules).
+    pub fn set_require_pcp_followup(
+        env: Env,
+        caller: Address,
+        discharge_plan_id: u64,
+        required: bool,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        let mut plan = storage::get_plan(&env, discharge_plan_id)?;
+        plan.require_pcp_followup = required;
+        storage::set_plan(&env, &plan);
+        Ok(())
+    }
+
+    /// Contract-wide PCP follow-up window, 14 days (the CMS default) when
+    /// unset. Admin-only.
+    pub fn set_pcp_followup_window_days(env: Env, admin: Address, days: u32) -> Result<(), Error> {
+        storage::require_admin(&env, &admin)?;
+        storage::set_pcp_followup_window_days(&env, days);
+        Ok(())
+    }
+
+    /// Records payer authorization status for one service type on a plan
+    /// (see `SERVICE_TYPE_*`). Gates `arrange_home_health` /
+    /// `coordinate_with_snf` when `set_require_authorization` has been set.
+    pub fn record_authorization(
+        env: Env,
+        caller: Address,
+        discharge_plan_id: u64,
+        service_type: u32,
+        authorized: bool,
+        auth_ref_hash: BytesN<32>,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        storage::get_plan(&env, discharge_plan_id)?;
+
+        storage::set_authorization(
+            &env,
+            discharge_plan_id,
+            service_type,
+            &AuthorizationRecord {
+                service_type,
+                authorized,
+                auth_ref_hash,
+                recorded_at: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// When set, `arrange_home_health` and `coordinate_with_snf` reject
+    /// with `Error::AuthorizationRequired` unless `record_authorization`
+    /// has recorded the matching service type as authorized.
+    pub fn set_require_authorization(
+        env: Env,
+        caller: Address,
+        discharge_plan_id: u64,
+        required: bool,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        let mut plan = storage::get_plan(&env, discharge_plan_id)?;
+        plan.require_authorization = required;
+        storage::set_plan(&env, &plan);
+        Ok(())
+    }
+
+    /// When `allowed` is `false` (the default), `schedule_followup_appointments`
+    /// rejects completed plans with `Error::AlreadyCompleted`. An admin can
+    /// set this to `true` to permit post-discharge scheduling corrections.
+    pub fn set_allow_scheduling_post_dc(
+        env: Env,
+        admin: Address,
+        allowed: bool,
+    ) -> Result<(), Error> {
+        storage::require_admin(&env, &admin)?;
+        storage::set_allow_scheduling_after_completion(&env, allowed);
+        Ok(())
+    }
+
+    /// Bounds how long after `actual_discharge_date` a completed plan can
+    /// still accept new follow-up appointments once
+    /// `set_allow_scheduling_post_dc` is on, so legitimate
+    /// just-after-discharge scheduling isn't indistinguishable from stale
+    /// edits long after the fact.
+    pub fn set_post_dc_scheduling_window(
+        env: Env,
+        admin: Address,
+        seconds: u64,
+    ) -> Result<(), Error> {
+        storage::require_admin(&env, &admin)?;
+        storage::set_post_dc_scheduling_window(&env, seconds);
+        Ok(())
+    }
+
+    /// Schedules a batch of follow-up appointments, rejecting the whole
+    /// batch with `Error::TooManyAppointments` if it would push the plan's
+    /// total (existing plus new) past the configured
+    /// `set_max_appointments_per_plan` limit.
+    pub fn schedule_followup_appointments(
+        env: Env,
+        caller: Address,
+        discharge_plan_id: u64,
+        new_appointments: Vec<(u64, u32, u64, BytesN<32>)>,
+    ) -> Result<Vec<u64>, Error> {
+        caller.require_auth();
+        let plan = storage::get_plan(&env, discharge_plan_id)?;
+        if plan.status == STAGE_COMPLETED {
+            if !storage::allow_scheduling_after_completion(&env) {
+                return Err(Error::AlreadyCompleted);
+            }
+            if let Some(window) = storage::post_discharge_scheduling_window(&env) {
+                let deadline = plan.actual_discharge_date.saturating_add(window);
+                if env.ledger().timestamp() > deadline {
+                    return Err(Error::AlreadyCompleted);
+                }
+            }
+        }
+
+        let mut existing = storage::appointments(&env, discharge_plan_id);
+        let max = storage::max_appointments_per_plan(&env);
+        if existing.len() as u64 + new_appointments.len() as u64 > max as u64 {
+            return Err(Error::TooManyAppointments);
+        }
+
+        let mut ids = Vec::new(&env);
+        for (provider_id, specialty, time, care_summary_hash) in new_appointments.iter() {
+            let id = storage::get_and_increment_appointment_counter(&env)?;
+            existing.push_back(FollowUpAppointment {
+                id,
+                provider_id,
+                specialty,
+                time,
+                care_summary_hash,
+                status: APPOINTMENT_STATUS_SCHEDULED,
+            });
+            ids.push_back(id);
+        }
+        storage::set_appointments(&env, discharge_plan_id, &existing);
+
+        Ok(ids)
+    }
+
+    /// Expands a recurring series (e.g. weekly oncology follow-ups) into
+    /// individual appointments spaced `interval_secs` apart, starting at
+    /// `first_time`, reusing the same max-per-plan check as
+    /// `schedule_followup_appointments`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn schedule_recurring_followup(
+        env: Env,
+        caller: Address,
+        discharge_plan_id: u64,
+        provider_id: u64,
+        specialty: u32,
+        first_time: u64,
+        interval_secs: u64,
+        occurrences: u32,
+    ) -> Result<Vec<u64>, Error> {
+        if occurrences == 0 || occurrences > MAX_RECURRING_OCCURRENCES {
+            return Err(Error::TooManyOccurrences);
+        }
+        if first_time <= env.ledger().timestamp() {
+            return Err(Error::InvalidInput);
+        }
+
+        let zero_hash = BytesN::from_array(&env, &[0u8; 32]);
+        let mut series = Vec::new(&env);
+        for i in 0..occurrences {
+            series.push_back((
+                provider_id,
+                specialty,
+                first_time + interval_secs * i as u64,
+                zero_hash.clone(),
+            ));
+        }
+
+        Self::schedule_followup_appointments(env, caller, discharge_plan_id, series)
+    }
+
+    /// Pairs of appointment ids whose `time` falls within `slot_secs` of
+    /// each other, a likely double-booking. `O(n^2)` over the plan's
+    /// appointments; fine for the per-plan volumes this contract expects.
+    pub fn get_appointment_conflicts(
+        env: Env,
+        discharge_plan_id: u64,
+        slot_secs: u64,
+    ) -> Result<Vec<(u64, u64)>, Error> {
+        storage::get_plan(&env, discharge_plan_id)?;
+        let appointments = storage::appointments(&env, discharge_plan_id);
+
+        let mut conflicts = Vec::new(&env);
+        for i in 0..appointments.len() {
+            for j in (i + 1)..appointments.len() {
+                let a = appointments.get(i).unwrap();
+                let b = appointments.get(j).unwrap();
+                let gap = a.time.abs_diff(b.time);
+                if gap <= slot_secs {
+                    conflicts.push_back((a.id, b.id));
+                }
+            }
+        }
+        Ok(conflicts)
+    }
+
+    pub fn get_followup_appointments(env: Env, discharge_plan_id: u64) -> Appointments {
+        storage::appointments(&env, discharge_plan_id)
+    }
+
+    /// Updates a follow-up appointment's adherence status (see
+    /// `APPOINTMENT_STATUS_*`). Rejects an unknown `status` code; silent
+    /// no-op if `appointment_id` isn't found.
+    pub fn update_appointment_status(
+        env: Env,
+        caller: Address,
+        discharge_plan_id: u64,
+        appointment_id: u64,
+        status: u32,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        storage::get_plan(&env, discharge_plan_id)?;
+        if status > APPOINTMENT_STATUS_CANCELLED {
+            return Err(Error::InvalidInput);
+        }
+
+        let mut list = storage::appointments(&env, discharge_plan_id);
+        for i in 0..list.len() {
+            let mut appointment = list.get(i).unwrap();
+            if appointment.id == appointment_id {
+                appointment.status = status;
+                list.set(i, appointment);
+                break;
+            }
+        }
+        storage::set_appointments(&env, discharge_plan_id, &list);
+
+        Ok(())
+    }
+
+    /// `(completed, total)` follow-up appointments for a plan, a key
+    /// readmission-risk predictor.
+    pub fn get_appointment_adherence(
+        env: Env,
+        discharge_plan_id: u64,
+    ) -> Result<(u32, u32), Error> {
+        storage::get_plan(&env, discharge_plan_id)?;
+
+        let appointments = storage::appointments(&env, discharge_plan_id);
+        let mut completed = 0u32;
+        for appointment in appointments.iter() {
+            if appointment.status == APPOINTMENT_STATUS_COMPLETED {
+                completed += 1;
+            }
+        }
+        Ok((completed, appointments.len()))
+    }
+
+    /// Moves every one of a plan's follow-up appointments from
+    /// `old_provider_id` to `new_provider_id` (e.g. when a provider leaves
+    /// the practice), returning how many were changed.
+    pub fn reassign_appointments_provider(
+        env: Env,
+        caller: Address,
+        discharge_plan_id: u64,
+        old_provider_id: u64,
+        new_provider_id: u64,
+    ) -> Result<u32, Error> {
+        caller.require_auth();
+        storage::get_plan(&env, discharge_plan_id)?;
+
+        let mut list = storage::appointments(&env, discharge_plan_id);
+        let mut changed = 0u32;
+        for i in 0..list.len() {
+            let mut appointment = list.get(i).unwrap();
+            if appointment.provider_id == old_provider_id {
+                appointment.provider_id = new_provider_id;
+                list.set(i, appointment);
+                changed += 1;
+            }
+        }
+        storage::set_appointments(&env, discharge_plan_id, &list);
+
+        env.events().publish(
+            (TOPIC_REASSIGN, caller),
+            (discharge_plan_id, old_provider_id, new_provider_id),
+        );
+
+        Ok(changed)
+    }
+
+    /// Carries a readmission forward onto a freshly initiated plan, copying
+    /// the patient/provider/destination and any still-open barriers and
+    /// future-dated follow-up appointments from `source_plan_id` so
+    /// coordinators don't have to re-enter unresolved work.
+    pub fn carry_forward_to_new_plan(
+        env: Env,
+        caller: Address,
+        source_plan_id: u64,
+        new_admission_date: u64,
+        new_expected_date: u64,
+    ) -> Result<u64, Error> {
+        caller.require_auth();
+        let source = storage::get_plan(&env, source_plan_id)?;
+
+        let new_id = Self::initiate_discharge_planning(
+            env.clone(),
+            caller,
+            source.patient_id,
+            source.attending_provider_id,
+            new_admission_date,
+            new_expected_date,
+            source.destination,
+        )?;
+
+        let mut open_barriers = Vec::new(&env);
+        for barrier in storage::barriers(&env, source_plan_id).iter() {
+            if !barrier.resolved {
+                open_barriers.push_back(barrier);
+            }
+        }
+        storage::set_barriers(&env, new_id, &open_barriers);
+
+        let now = env.ledger().timestamp();
+        let mut future_appointments = Vec::new(&env);
+        for appointment in storage::appointments(&env, source_plan_id).iter() {
+            if appointment.time > now {
+                let id = storage::get_and_increment_appointment_counter(&env)?;
+                future_appointments.push_back(FollowUpAppointment {
+                    id,
+                    provider_id: appointment.provider_id,
+                    specialty: appointment.specialty,
+                    time: appointment.time,
+                    care_summary_hash: appointment.care_summary_hash,
+                    status: APPOINTMENT_STATUS_SCHEDULED,
+                });
+            }
+        }
+        storage::set_appointments(&env, new_id, &future_appointments);
+
+        Ok(new_id)
+    }
+
+    /// Records a readmission-risk score for the plan. A score at or above
+    /// `ESCALATION_SCORE_THRESHOLD` sets the plan's `escalated` flag and
+    /// adds it to the care-management worklist (`get_escalated_plan_ids`).
+    pub fn track_readmission_risk(
+        env: Env,
+        caller: Address,
+        discharge_plan_id: u64,
+        score: u32,
+        factors: u32,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        let mut plan = storage::get_plan(&env, discharge_plan_id)?;
+
+        storage::push_risk_record(
+            &env,
+            discharge_plan_id,
+            RiskRecord {
+                score,
+                recorded_at: env.ledger().timestamp(),
+                factors,
+            },
+        );
+
+        if score >= ESCALATION_SCORE_THRESHOLD && !plan.escalated {
+            plan.escalated = true;
+            storage::set_plan(&env, &plan);
+            storage::add_to_escalated_index(&env, discharge_plan_id);
+
+            env.events()
+                .publish((TOPIC_ESCALATE, caller), discharge_plan_id);
+        }
+
+        Ok(())
+    }
+
+    /// Computes the standard LACE readmission-risk index: Length of stay
+    /// (from the plan's admission/expected-discharge window, 0/1/2/3/4-6/
+    /// 7-13/14+ days scoring 0/1/2/3/4/5/7), Acuity (capped at 3),
+    /// Comorbidities (capped at 5), and ED visits (capped at 4). Stores the
+    /// result on the plan.
+    pub fn compute_lace_index(
+        env: Env,
+        caller: Address,
+        discharge_plan_id: u64,
+        acuity: u32,
+        comorbidities: u32,
+        ed_visits: u32,
+    ) -> Result<u32, Error> {
+        caller.require_auth();
+        let mut plan = storage::get_plan(&env, discharge_plan_id)?;
+
+        let length_of_stay_days =
+            plan.expected_discharge_date.saturating_sub(plan.admission_date) / 86_400;
+        let l = match length_of_stay_days {
+            0 => 0,
+            1 => 1,
+            2 => 2,
+            3 => 3,
+            4..=6 => 4,
+            7..=13 => 5,
+            _ => 7,
+        };
+
+        let score = l + acuity.min(3) + comorbidities.min(5) + ed_visits.min(4);
+        plan.lace_index = Some(score);
+        storage::set_plan(&env, &plan);
+
+        Ok(score)
+    }
+
+    /// For population analytics: counts, across the given plans' latest
+    /// risk record, how many have each of the `RISK_FACTOR_COUNT` factor
+    /// bits set. Plans with no risk history are skipped.
+    pub fn get_risk_factor_prevalence(env: Env, ids: Vec<u64>) -> Vec<u32> {
+        let mut counts = Vec::new(&env);
+        for _ in 0..RISK_FACTOR_COUNT {
+            counts.push_back(0);
+        }
+        for plan_id in ids.iter() {
+            let Some(latest) = storage::risk_history(&env, plan_id).last() else {
+                continue;
+            };
+            for bit in 0..RISK_FACTOR_COUNT {
+                if latest.factors & (1 << bit) != 0 {
+                    let count = counts.get_unchecked(bit);
+                    counts.set(bit, count + 1);
+                }
+            }
+        }
+        counts
+    }
+
+    /// Weights each set bit of `risk_factors` by the configured per-factor
+    /// weight (equal by default, see `set_risk_factor_weights`) and
+    /// normalizes to a 0-100 score, so a fully-weighted bitmap always caps
+    /// at 100 regardless of how the weights were tuned.
+    pub fn compute_readmission_risk(env: Env, risk_factors: u32) -> u32 {
+        let weights = storage::risk_factor_weights(&env);
+        let weight_sum: u32 = weights.iter().sum();
+        if weight_sum == 0 {
+            return 0;
+        }
+
+        let mut weighted_sum: u32 = 0;
+        for (bit, weight) in weights.iter().enumerate() {
+            if risk_factors & (1 << bit) != 0 {
+                weighted_sum += weight;
+            }
+        }
+
+        (weighted_sum * 100 / weight_sum).min(100)
+    }
+
+    /// Lets a facility tune how much each of the four readmission risk
+    /// factors contributes to `compute_readmission_risk`, instead of the
+    /// equal-weight default. Each weight is capped at
+    /// `MAX_RISK_FACTOR_WEIGHT` so `compute_readmission_risk`'s
+    /// `weighted_sum * 100` can never overflow `u32`, even with every
+    /// factor bit set.
+    pub fn set_risk_factor_weights(
+        env: Env,
+        admin: Address,
+        weights: Vec<u32>,
+    ) -> Result<(), Error> {
+        storage::require_admin(&env, &admin)?;
+        if weights.iter().any(|weight| weight > MAX_RISK_FACTOR_WEIGHT) {
+            return Err(Error::InvalidWeights);
+        }
+        storage::set_risk_factor_weights(&env, weights);
+        Ok(())
+    }
+
+    /// Raw `(plan_counter, appointment_counter)` totals, for off-chain
+    /// dashboards that want overall volume without paging through every id.
+    pub fn get_counters(env: Env) -> (u64, u64) {
+        (storage::plan_counter(&env), storage::appointment_counter(&env))
+    }
+
+    /// Worklist distinct from "active plans": ids of plans that have at
+    /// least one readiness assessment but have not reached
+    /// `STAGE_COMPLETED`. Scans all plan ids, so cost grows with total plan
+    /// count; fine for the worklist sizes this contract expects.
+    pub fn get_assessed_incomplete_plan_ids(env: Env, start: u32, limit: u32) -> Vec<u64> {
+        let mut matching = Vec::new(&env);
+        for plan_id in 1..=storage::plan_counter(&env) {
+            let Ok(plan) = storage::get_plan(&env, plan_id) else {
+                continue;
+            };
+            if plan.status == STAGE_COMPLETED {
+                continue;
+            }
+            if !storage::readiness_history(&env, plan_id).is_empty() {
+                matching.push_back(plan_id);
+            }
+        }
+        paginate(&env, &matching, start, limit)
+    }
+
+    pub fn get_escalated_plan_ids(env: Env, start: u32, limit: u32) -> Vec<u64> {
+        let ids = storage::escalated_plan_ids(&env);
+        paginate(&env, &ids, start, limit)
+    }
+
+    /// Plans whose `created_at` falls within `[start_ts, end_ts]`, for daily
+    /// census reports. Scans all plan ids, so cost grows with total plan
+    /// count; fine for the worklist sizes this contract expects.
+    pub fn get_plans_created_between(
+        env: Env,
+        start_ts: u64,
+        end_ts: u64,
+        start_idx: u32,
+        limit: u32,
+    ) -> Result<Vec<u64>, Error> {
+        if start_ts > end_ts {
+            return Err(Error::InvalidInput);
+        }
+
+        let mut matching = Vec::new(&env);
+        for plan_id in 1..=storage::plan_counter(&env) {
+            let Ok(plan) = storage::get_plan(&env, plan_id) else {
+                continue;
+            };
+            if plan.created_at >= start_ts && plan.created_at <= end_ts {
+                matching.push_back(plan_id);
+            }
+        }
+        Ok(paginate(&env, &matching, start_idx, limit))
+    }
+
+    pub fn arrange_home_health(
+        env: Env,
+        caller: Address,
+        discharge_plan_id: u64,
+        frequency_per_week: u32,
+        duration_weeks: u32,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        let plan = storage::get_plan(&env, discharge_plan_id)?;
+        if plan.require_authorization
+            && !storage::authorization(&env, discharge_plan_id, SERVICE_TYPE_HOME_HEALTH)
+                .map(|record| record.authorized)
+                .unwrap_or(false)
+        {
+            return Err(Error::AuthorizationRequired);
+        }
+        storage::set_home_health_arrangement(
+            &env,
+            discharge_plan_id,
+            &HomeHealthArrangement {
+                frequency_per_week,
+                duration_weeks,
+                visits_completed: 0,
+            },
+        );
+        Ok(())
+    }
+
+    pub fn register_caregiver(
+        env: Env,
+        caller: Address,
+        discharge_plan_id: u64,
+        caregiver_id: BytesN<32>,
+        relationship: u32,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        storage::get_plan(&env, discharge_plan_id)?;
+
+        if relationship > MAX_RELATIONSHIP_CODE {
+            return Err(Error::InvalidInput);
+        }
+
+        storage::push_caregiver(
+            &env,
+            discharge_plan_id,
+            Caregiver {
+                caregiver_id,
+                relationship,
+            },
+        );
+        Ok(())
+    }
+
+    pub fn get_caregivers(env: Env, discharge_plan_id: u64) -> Caregivers {
+        storage::caregivers(&env, discharge_plan_id)
+    }
+
+    /// Advisory 0-100 support-system adequacy suggestion, derived from the
+    /// number and relationship closeness of registered caregivers: closer
+    /// relationships (spouse, child, parent) contribute more than `Other`.
+    /// Not stored automatically — callers decide whether to feed it into
+    /// `assess_discharge_readiness`'s sub-scores.
+    pub fn suggest_support_score(env: Env, discharge_plan_id: u64) -> Result<u32, Error> {
+        storage::get_plan(&env, discharge_plan_id)?;
+
+        let mut score = 0u32;
+        for caregiver in storage::caregivers(&env, discharge_plan_id).iter() {
+            score += match caregiver.relationship {
+                0 => 40,
+                1 => 30,
+                2 => 20,
+                _ => 10,
+            };
+        }
+        Ok(score.min(100))
+    }
+
+    pub fn record_home_health_visit(
+        env: Env,
+        caller: Address,
+        discharge_plan_id: u64,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        let mut arrangement =
+            storage::home_health_arrangement(&env, discharge_plan_id).ok_or(Error::PlanNotFound)?;
+        arrangement.visits_completed += 1;
+        storage::set_home_health_arrangement(&env, discharge_plan_id, &arrangement);
+        Ok(())
+    }
+
+    /// Planned visits (`frequency_per_week * duration_weeks`) minus visits
+    /// recorded so far, floored at 0.
+    pub fn get_remaining_home_health_visits(
+        env: Env,
+        discharge_plan_id: u64,
+    ) -> Result<u32, Error> {
+        let arrangement =
+            storage::home_health_arrangement(&env, discharge_plan_id).ok_or(Error::PlanNotFound)?;
+        let planned = arrangement.frequency_per_week * arrangement.duration_weeks;
+        Ok(planned.saturating_sub(arrangement.visits_completed))
+    }
+
+    /// Completed visits over planned visits as a percentage, capped at 100,
+    /// for agency fulfillment-based payment.
+    pub fn get_home_health_fulfillment_pct(env: Env, discharge_plan_id: u64) -> Result<u32, Error> {
+        let arrangement =
+            storage::home_health_arrangement(&env, discharge_plan_id).ok_or(Error::PlanNotFound)?;
+        let planned = arrangement.frequency_per_week * arrangement.duration_weeks;
+        if planned == 0 {
+            return Ok(0);
+        }
+        let pct = arrangement.visits_completed * 100 / planned;
+        Ok(pct.min(100))
+    }
+
+    pub fn assess_discharge_readiness(
+        env: Env,
+        caller: Address,
+        discharge_plan_id: u64,
+        sub_scores: Vec<u32>,
+    ) -> Result<u32, Error> {
+        caller.require_auth();
+        let total = sub_scores.iter().sum::<u32>() / sub_scores.len();
+        Self::record_readiness(&env, caller, discharge_plan_id, sub_scores, total)
+    }
+
+    /// Like `assess_discharge_readiness`, but rejects an all-zero
+    /// submission with `Error::InvalidScore` instead of silently recording
+    /// a 0 total, which otherwise reads the same as "not yet assessed".
+    pub fn assess_dc_readiness_strict(
+        env: Env,
+        caller: Address,
+        discharge_plan_id: u64,
+        sub_scores: Vec<u32>,
+    ) -> Result<u32, Error> {
+        caller.require_auth();
+        if sub_scores.iter().all(|score| score == 0) {
+            return Err(Error::InvalidScore);
+        }
+
+        let total = sub_scores.iter().sum::<u32>() / sub_scores.len();
+        Self::record_readiness(&env, caller, discharge_plan_id, sub_scores, total)
+    }
+
+    /// Lets separate specialists each assess one readiness domain (e.g. PT
+    /// for functional status, pharmacist for medications). The total is
+    /// only recomputed once all four domains have been assessed by someone.
+    pub fn assess_readiness_domain(
+        env: Env,
+        caller: Address,
+        discharge_plan_id: u64,
+        domain: u32,
+        score: u32,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        storage::get_plan(&env, discharge_plan_id)?;
+
+        if domain >= READINESS_DOMAIN_COUNT || score > 100 {
+            return Err(Error::InvalidScore);
+        }
+
+        let mut progress = storage::domain_progress(&env, discharge_plan_id);
+        progress.scores.set(domain, score);
+        progress.has_assessor.set(domain, true);
+
+        if progress.is_complete() {
+            let scores = progress.scores;
+            storage::clear_domain_progress(&env, discharge_plan_id);
+            let total = scores.iter().sum::<u32>() / scores.len();
+            Self::record_readiness(&env, caller, discharge_plan_id, scores, total)?;
+        } else {
+            storage::set_domain_progress(&env, discharge_plan_id, &progress);
+        }
+
+        Ok(())
+    }
+
+    /// Records a vital-signs stability check and, if a readiness assessment
+    /// already exists, nudges its medical-stability sub-score by
+    /// `VITALS_STABILITY_STEP` (up when `stable`, down otherwise) via a new
+    /// append-only readiness entry rather than editing the prior one.
+    pub fn record_vitals_check(
+        env: Env,
+        caller: Address,
+        discharge_plan_id: u64,
+        stable: bool,
+        checked_at: u64,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        storage::get_plan(&env, discharge_plan_id)?;
+
+        storage::push_vitals_check(&env, discharge_plan_id, VitalsCheck { stable, checked_at });
+
+        if let Ok(latest) = Self::get_latest_readiness(env.clone(), discharge_plan_id) {
+            let mut sub_scores = latest.sub_scores;
+            let current = sub_scores.get_unchecked(MEDICAL_STABILITY_DOMAIN as u32);
+            let adjusted = if stable {
+                (current + VITALS_STABILITY_STEP).min(100)
+            } else {
+                current.saturating_sub(VITALS_STABILITY_STEP)
+            };
+            sub_scores.set(MEDICAL_STABILITY_DOMAIN as u32, adjusted);
+            let total = sub_scores.iter().sum::<u32>() / sub_scores.len();
+            Self::record_readiness(&env, caller, discharge_plan_id, sub_scores, total)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn get_vitals_checks(env: Env, discharge_plan_id: u64) -> VitalsChecks {
+        storage::vitals_checks(&env, discharge_plan_id)
+    }
+
+    /// Overrides the readiness threshold used for `is_ready` on plans bound
+    /// for `destination` (e.g. a higher bar for home than for SNF, where
+    /// professional care continues). A per-plan override takes precedence;
+    /// when unset, the contract-wide threshold and then
+    /// `DEFAULT_READINESS_THRESHOLD` apply (see `effective_readiness_threshold`).
+    pub fn set_readiness_threshold_by_dest(
+        env: Env,
+        admin: Address,
+        destination: u32,
+        threshold: u32,
+    ) -> Result<(), Error> {
+        storage::require_admin(&env, &admin)?;
+        storage::set_readiness_threshold(&env, destination, threshold);
+        Ok(())
+    }
+
+    /// Overrides the readiness threshold contract-wide, used whenever a
+    /// plan has no per-plan or per-destination override set. Falls back to
+    /// `DEFAULT_READINESS_THRESHOLD` when unset. When `recompute_open_plans`
+    /// is set, also re-evaluates the latest readiness assessment of every
+    /// non-completed plan (up to `FULL_EXPORT_MAX_ITEMS`, oldest-id-first)
+    /// against its effective threshold (see `effective_readiness_threshold`;
+    /// an uncosigned assessment stays not ready), so a lowered bar doesn't leave open
+    /// plans stuck on a stale `is_ready`, and republishes `TOPIC_READY` for
+    /// each one that changed.
+    pub fn set_global_readiness_threshold(
+        env: Env,
+        admin: Address,
+        threshold: u32,
+        recompute_open_plans: bool,
+    ) -> Result<(), Error> {
+        storage::require_admin(&env, &admin)?;
+        storage::set_global_readiness_threshold(&env, threshold);
+
+        if recompute_open_plans {
+            let mut scanned = 0u32;
+            for plan_id in 1..=storage::plan_counter(&env) {
+                if scanned >= FULL_EXPORT_MAX_ITEMS {
+                    break;
+                }
+                let Ok(plan) = storage::get_plan(&env, plan_id) else {
+                    continue;
+                };
+                if plan.status == STAGE_COMPLETED {
+                    continue;
+                }
+                scanned += 1;
+
+                let Some(mut latest) = storage::readiness_history(&env, plan_id).last() else {
+                    continue;
+                };
+                let effective_threshold = effective_readiness_threshold(&env, &plan);
+                let recomputed_ready = latest.total >= effective_threshold && latest.cosigned;
+                if recomputed_ready != latest.is_ready {
+                    latest.is_ready = recomputed_ready;
+                    storage::set_latest_readiness(&env, plan_id, latest.clone());
+                    env.events()
+                        .publish((TOPIC_READY, admin.clone()), (plan_id, latest.total));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Overrides the readiness threshold for a single plan, taking
+    /// precedence over any destination or global override.
+    pub fn set_readiness_threshold_for_plan(
+        env: Env,
+        admin: Address,
+        discharge_plan_id: u64,
+        threshold: u32,
+    ) -> Result<(), Error> {
+        storage::require_admin(&env, &admin)?;
+        storage::get_plan(&env, discharge_plan_id)?;
+        storage::set_plan_readiness_threshold(&env, discharge_plan_id, threshold);
+        Ok(())
+    }
+
+    /// Resolves the readiness threshold that actually applies to a plan,
+    /// per the precedence documented on `effective_readiness_threshold`.
+    pub fn get_effective_threshold(env: Env, discharge_plan_id: u64) -> Result<u32, Error> {
+        let plan = storage::get_plan(&env, discharge_plan_id)?;
+        Ok(effective_readiness_threshold(&env, &plan))
+    }
+
+    /// Toggles the regulatory requirement that a readiness assessment be
+    /// co-signed by a second, distinct clinician before `is_ready` becomes
+    /// authoritative. Applies to assessments recorded after the change.
+    pub fn require_readiness_cosign(env: Env, admin: Address, required: bool) -> Result<(), Error> {
+        storage::require_admin(&env, &admin)?;
+        storage::set_readiness_cosign_required(&env, required);
+        Ok(())
+    }
+
+    /// Second step of the cosign flow: a clinician distinct from the
+    /// original assessor confirms the plan's latest readiness assessment,
+    /// making its `is_ready` authoritative if `total` clears the threshold.
+    /// Rejects a cosign attempt from the original assessor with
+    /// `Error::CosignRequired`.
+    pub fn cosign_readiness_assessment(
+        env: Env,
+        caller: Address,
+        discharge_plan_id: u64,
+    ) -> Result<bool, Error> {
+        caller.require_auth();
+        let plan = storage::get_plan(&env, discharge_plan_id)?;
+        let mut latest = Self::get_latest_readiness(env.clone(), discharge_plan_id)?;
+
+        if latest.assessor == caller {
+            return Err(Error::CosignRequired);
+        }
+
+        let threshold = effective_readiness_threshold(&env, &plan);
+        latest.cosigned = true;
+        latest.is_ready = latest.total >= threshold;
+        storage::set_latest_readiness(&env, discharge_plan_id, latest.clone());
+
+        Ok(latest.is_ready)
+    }
+
+    /// Grants `physician` standing to call `override_readiness`. Admin-only.
+    pub fn register_physician(env: Env, admin: Address, physician: Address) -> Result<(), Error> {
+        storage::require_admin(&env, &admin)?;
+        storage::set_physician(&env, &physician, true);
+        Ok(())
+    }
+
+    /// Bulk-registers home-health/SNF agencies (e.g. when onboarding a new
+    /// facility), capped at `MAX_BATCH_REGISTRATION` per call. Admin-only.
+    pub fn register_agencies_batch(
+        env: Env,
+        admin: Address,
+        ids: Vec<BytesN<32>>,
+    ) -> Result<(), Error> {
+        storage::require_admin(&env, &admin)?;
+        if ids.len() > MAX_BATCH_REGISTRATION {
+            return Err(Error::TooManyOccurrences);
+        }
+        for id in ids.iter() {
+            storage::set_registered_agency(&env, &id, true);
+        }
+        Ok(())
+    }
+
+    pub fn is_agency_registered(env: Env, agency_id: BytesN<32>) -> bool {
+        storage::is_registered_agency(&env, &agency_id)
+    }
+
+    /// Bulk-registers DME suppliers, capped at `MAX_BATCH_REGISTRATION` per
+    /// call. Admin-only.
+    pub fn register_suppliers_batch(
+        env: Env,
+        admin: Address,
+        ids: Vec<BytesN<32>>,
+    ) -> Result<(), Error> {
+        storage::require_admin(&env, &admin)?;
+        if ids.len() > MAX_BATCH_REGISTRATION {
+            return Err(Error::TooManyOccurrences);
+        }
+        for id in ids.iter() {
+            storage::set_registered_supplier(&env, &id, true);
+        }
+        Ok(())
+    }
+
+    pub fn is_supplier_registered(env: Env, supplier_id: BytesN<32>) -> bool {
+        storage::is_registered_supplier(&env, &supplier_id)
+    }
+
+    /// Records a physician's clinical-judgment override of the readiness
+    /// gate, authoritative regardless of the computed score or its
+    /// freshness. Restricted to addresses registered via
+    /// `register_physician`; `justification_code` is recorded alongside the
+    /// override for audit purposes.
+    pub fn override_readiness(
+        env: Env,
+        caller: Address,
+        discharge_plan_id: u64,
+        ready: bool,
+        justification_code: u32,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        if !storage::is_physician(&env, &caller) {
+            return Err(Error::NotAuthorized);
+        }
+        storage::get_plan(&env, discharge_plan_id)?;
+
+        let override_record = ReadinessOverride {
+            ready,
+            justification_code,
+            overridden_by: caller.clone(),
+            overridden_at: env.ledger().timestamp(),
+        };
+        storage::set_readiness_override(&env, discharge_plan_id, &override_record);
+        storage::push_override_log(&env, discharge_plan_id, OVERRIDE_TYPE_READINESS, caller.clone());
+
+        env.events().publish(
+            (TOPIC_OVERRIDE, discharge_plan_id),
+            (ready, justification_code, caller),
+        );
+
+        Ok(())
+    }
+
+    pub fn set_readiness_preset(
+        env: Env,
+        admin: Address,
+        preset_id: u32,
+        weights: Vec<u32>,
+    ) -> Result<(), Error> {
+        storage::require_admin(&env, &admin)?;
+        storage::set_readiness_preset(&env, preset_id, weights);
+        Ok(())
+    }
+
+    /// Like `assess_discharge_readiness`, but weights the four sub-scores
+    /// by a preset registered via `set_readiness_preset` instead of
+    /// averaging them equally. When `strict` is set, a preset with any
+    /// zero weight is rejected with `Error::InvalidWeights` instead of
+    /// silently letting that weight zero out its domain's influence.
+    pub fn assess_dc_readiness_with_preset(
+        env: Env,
+        caller: Address,
+        discharge_plan_id: u64,
+        sub_scores: Vec<u32>,
+        preset_id: u32,
+        strict: bool,
+    ) -> Result<u32, Error> {
+        caller.require_auth();
+        let weights = storage::readiness_preset(&env, preset_id).ok_or(Error::UnknownPreset)?;
+
+        for score in sub_scores.iter() {
+            if score > 100 {
+                return Err(Error::InvalidScore);
+            }
+        }
+
+        if strict && weights.iter().any(|weight| weight == 0) {
+            return Err(Error::InvalidWeights);
+        }
+
+        let weight_sum: u32 = weights.iter().sum();
+        let weighted_sum: u32 = sub_scores
+            .iter()
+            .zip(weights.iter())
+            .map(|(score, weight)| score * weight)
+            .sum();
+        let total = weighted_sum.checked_div(weight_sum).unwrap_or(0);
+
+        Self::record_readiness(&env, caller, discharge_plan_id, sub_scores, total)
+    }
+
+    fn record_readiness(
+        env: &Env,
+        caller: Address,
+        discharge_plan_id: u64,
+        sub_scores: Vec<u32>,
+        total: u32,
+    ) -> Result<u32, Error> {
+        let mut plan = storage::get_plan(env, discharge_plan_id)?;
+        require_admitted(env, &plan)?;
+        if plan.status == STAGE_COMPLETED {
+            return Err(Error::InvalidStateTransition);
+        }
+
+        for score in sub_scores.iter() {
+            if score > 100 {
+                return Err(Error::InvalidScore);
+            }
+        }
+
+        let threshold = effective_readiness_threshold(env, &plan);
+        let cosign_required = storage::readiness_cosign_required(env);
+        let assessment = ReadinessAssessment {
+            sub_scores,
+            total,
+            is_ready: total >= threshold && !cosign_required,
+            assessed_at: env.ledger().timestamp(),
+            assessor: caller.clone(),
+            cosigned: !cosign_required,
+        };
+        storage::push_readiness(env, discharge_plan_id, assessment);
+
+        let from_stage = plan.status;
+        plan.status = STAGE_ASSESSED;
+        storage::set_plan(env, &plan);
+        storage::push_event_log(env, discharge_plan_id, EVENT_READINESS_ASSESSED);
+        notify_subscribers(env, discharge_plan_id, EVENT_READINESS_ASSESSED);
+        if from_stage != STAGE_ASSESSED {
+            emit_stage_changed(env, discharge_plan_id, from_stage, STAGE_ASSESSED, caller.clone());
+        }
+
+        env.events()
+            .publish((TOPIC_READY, caller), (discharge_plan_id, total));
+
+        Ok(total)
+    }
+
+    /// Serializes a plan's core state (plan, readiness history, orders) as
+    /// an XDR-encoded blob so it can be moved to another contract instance
+    /// via `import_plan_blob`, e.g. for facility mergers or sharding.
+    pub fn export_plan_blob(env: Env, admin: Address, discharge_plan_id: u64) -> Result<Bytes, Error> {
+        storage::require_admin(&env, &admin)?;
+
+        let bundle = PlanExportBundle {
+            plan: storage::get_plan(&env, discharge_plan_id)?,
+            readiness_history: storage::readiness_history(&env, discharge_plan_id),
+            orders: storage::orders(&env, discharge_plan_id),
+        };
+        Ok(bundle.to_xdr(&env))
+    }
+
+    /// Reconstructs a plan exported by `export_plan_blob` under a fresh id
+    /// in this contract instance.
+    pub fn import_plan_blob(env: Env, admin: Address, blob: Bytes) -> Result<u64, Error> {
+        storage::require_admin(&env, &admin)?;
+
+        let bundle = PlanExportBundle::from_xdr(&env, &blob).map_err(|_| Error::InvalidInput)?;
+
+        let new_id = storage::get_and_increment_plan_counter(&env)?;
+        let mut plan = bundle.plan;
+        plan.id = new_id;
+        storage::set_plan(&env, &plan);
+
+        for assessment in bundle.readiness_history.iter() {
+            storage::push_readiness(&env, new_id, assessment);
+        }
+        for order in bundle.orders.iter() {
+            storage::push_order(&env, new_id, order);
+        }
+
+        Ok(new_id)
+    }
+
+    /// Bypasses normal role checks for a clinical emergency, returning the
+    /// plan while emitting a high-visibility `TOPIC_EMERGENCY_ACCESS` event that
+    /// captures the caller and justification for later audit.
+    pub fn emergency_read_plan(
+        env: Env,
+        caller: Address,
+        discharge_plan_id: u64,
+        justification_code: u32,
+    ) -> Result<DischargePlan, Error> {
+        caller.require_auth();
+        let plan = storage::get_plan(&env, discharge_plan_id)?;
+
+        storage::push_override_log(
+            &env,
+            discharge_plan_id,
+            OVERRIDE_TYPE_EMERGENCY_ACCESS,
+            caller.clone(),
+        );
+        env.events().publish(
+            (TOPIC_EMERGENCY_ACCESS, caller),
+            (discharge_plan_id, justification_code),
+        );
+
+        Ok(plan)
+    }
+
+    /// Number of readiness entries recorded for a plan. Every reassessment
+    /// appends a new entry via `record_readiness` rather than overwriting
+    /// the prior one, so this also serves as an amendment count for audit
+    /// purposes (the only in-place mutations anywhere in this contract are
+    /// `cosign_readiness_assessment` and the `set_global_readiness_threshold`
+    /// recompute updating the *latest* entry's sign-off / `is_ready` flags,
+    /// never altering its scores).
+    pub fn get_readiness_amendment_count(env: Env, discharge_plan_id: u64) -> u32 {
+        storage::readiness_history(&env, discharge_plan_id).len()
+    }
+
+    /// Average per-day change in readiness `total` from the first to the
+    /// most recent assessment, using `assessed_at` timestamps; a positive
+    /// value predicts imminent readiness, negative predicts decline.
+    /// Requires at least two assessments (`Error::InsufficientHistory`
+    /// otherwise).
+    pub fn get_readiness_velocity(env: Env, discharge_plan_id: u64) -> Result<i32, Error> {
+        let history = storage::readiness_history(&env, discharge_plan_id);
+        if history.len() < 2 {
+            return Err(Error::InsufficientHistory);
+        }
+
+        let first = history.get(0).unwrap();
+        let last = history.get(history.len() - 1).unwrap();
+
+        let delta_total = last.total as i32 - first.total as i32;
+        let days = ((last.assessed_at.saturating_sub(first.assessed_at)) / 86_400).max(1) as i32;
+
+        Ok(delta_total / days)
+    }
+
+    /// 0-100 confidence in the readiness trajectory: 100 minus the average
+    /// absolute swing in `total` between consecutive assessments, so a
+    /// score that oscillates back and forth near the threshold scores
+    /// lower than one that climbs (or holds) steadily. Requires at least
+    /// two assessments (`Error::InsufficientHistory` otherwise).
+    pub fn get_readiness_confidence(env: Env, discharge_plan_id: u64) -> Result<u32, Error> {
+        let history = storage::readiness_history(&env, discharge_plan_id);
+        if history.len() < 2 {
+            return Err(Error::InsufficientHistory);
+        }
+
+        let mut swing_sum: u32 = 0;
+        let mut previous = history.get(0).unwrap().total;
+        for assessment in history.iter().skip(1) {
+            swing_sum += previous.abs_diff(assessment.total);
+            previous = assessment.total;
+        }
+        let avg_swing = swing_sum / (history.len() - 1);
+
+        Ok(100u32.saturating_sub(avg_swing))
+    }
+
+    /// Indices (0..4) of the latest readiness assessment's sub-scores that
+    /// fall below `floor`, for surfacing which domain is blocking discharge
+    /// (e.g. functional status vs. medication reconciliation).
+    pub fn get_readiness_gaps(
+        env: Env,
+        discharge_plan_id: u64,
+        floor: u32,
+    ) -> Result<Vec<u32>, Error> {
+        let latest = Self::get_latest_readiness(env.clone(), discharge_plan_id)?;
+        let mut gaps = Vec::new(&env);
+        for (index, score) in latest.sub_scores.iter().enumerate() {
+            if score < floor {
+                gaps.push_back(index as u32);
+            }
+        }
+        Ok(gaps)
+    }
+
+    pub fn get_latest_readiness(
+        env: Env,
+        discharge_plan_id: u64,
+    ) -> Result<ReadinessAssessment, Error> {
+        storage::readiness_history(&env, discharge_plan_id)
+            .last()
+            .ok_or(Error::PlanNotFound)
+    }
+
+    /// Thin convenience over `get_latest_readiness` for radar-chart UIs
+    /// that only need the four sub-scores, not the full struct.
+    pub fn get_readiness_breakdown(env: Env, discharge_plan_id: u64) -> Result<Vec<u32>, Error> {
+        Ok(Self::get_latest_readiness(env, discharge_plan_id)?.sub_scores)
+    }
+
+    /// Single 0-100 KPI combining readiness total (40%), education
+    /// completion percentage (25%), whether any discharge order has been
+    /// placed (20%), and whether any follow-up has been scheduled (15%).
+    pub fn get_discharge_quality_score(env: Env, discharge_plan_id: u64) -> Result<u32, Error> {
+        storage::get_plan(&env, discharge_plan_id)?;
+
+        let readiness = Self::get_latest_readiness(env.clone(), discharge_plan_id)
+            .map(|assessment| assessment.total)
+            .unwrap_or(0)
+            .min(100);
+
+        let education_records = storage::education_records(&env, discharge_plan_id);
+        let education = if education_records.is_empty() {
+            0
+        } else {
+            let completed = education_records
+                .iter()
+                .filter(|record| record.completed)
+                .count() as u32;
+            completed * 100 / education_records.len()
+        };
+
+        let orders = if storage::orders(&env, discharge_plan_id).is_empty() {
+            0
+        } else {
+            100
+        };
+
+        let followup = if storage::appointments(&env, discharge_plan_id).is_empty() {
+            0
+        } else {
+            100
+        };
+
+        Ok((readiness * 40 + education * 25 + orders * 20 + followup * 15) / 100)
+    }
+
+    /// Registers `subscriber` to receive a `notify(plan_id, event_code)`
+    /// cross-call whenever this plan logs an event. Rejects with
+    /// `Error::InvalidInput` once `MAX_SUBSCRIBERS_PER_PLAN` is reached.
+    pub fn subscribe_plan_events(
+        env: Env,
+        caller: Address,
+        discharge_plan_id: u64,
+        subscriber: Address,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        storage::get_plan(&env, discharge_plan_id)?;
+
+        let mut subscribers = storage::plan_subscribers(&env, discharge_plan_id);
+        if subscribers.iter().any(|existing| existing == subscriber) {
+            return Ok(());
+        }
+        if subscribers.len() >= MAX_SUBSCRIBERS_PER_PLAN {
+            return Err(Error::InvalidInput);
+        }
+        subscribers.push_back(subscriber);
+        storage::set_plan_subscribers(&env, discharge_plan_id, &subscribers);
+
+        Ok(())
+    }
+
+    pub fn unsubscribe_plan_events(
+        env: Env,
+        caller: Address,
+        discharge_plan_id: u64,
+        subscriber: Address,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        storage::get_plan(&env, discharge_plan_id)?;
+
+        let subscribers = storage::plan_subscribers(&env, discharge_plan_id);
+        let mut remaining = Subscribers::new(&env);
+        for existing in subscribers.iter() {
+            if existing != subscriber {
+                remaining.push_back(existing);
+            }
+        }
+        storage::set_plan_subscribers(&env, discharge_plan_id, &remaining);
+
+        Ok(())
+    }
+
+    pub fn create_discharge_orders(
+        env: Env,
+        caller: Address,
+        discharge_plan_id: u64,
+        order_type: u32,
+        details_hash: BytesN<32>,
+    ) -> Result<u64, Error> {
+        Self::create_dc_orders_with_scheme(
+            env,
+            caller,
+            discharge_plan_id,
+            order_type,
+            details_hash,
+            DEFAULT_ENCRYPTION_SCHEME,
+        )
+    }
+
+    /// Like `create_discharge_orders`, but records which off-chain
+    /// encryption `scheme` was used for `details_hash`'s referenced
+    /// document.
+    pub fn create_dc_orders_with_scheme(
+        env: Env,
+        caller: Address,
+        discharge_plan_id: u64,
+        order_type: u32,
+        details_hash: BytesN<32>,
+        scheme: u32,
+    ) -> Result<u64, Error> {
+        caller.require_auth();
+        let mut plan = storage::get_plan(&env, discharge_plan_id)?;
+        require_admitted(&env, &plan)?;
+        if plan.status == STAGE_COMPLETED {
+            return Err(Error::InvalidStateTransition);
+        }
+        if is_zero_hash(&details_hash) {
+            return Err(Error::MissingSummary);
+        }
+
+        let order = DischargeOrder {
+            id: discharge_plan_id * 1_000_000 + storage::orders(&env, discharge_plan_id).len() as u64,
+            order_type,
+            details_hash,
+            created_at: env.ledger().timestamp(),
+            acted_by: caller.clone(),
+            status: None,
+            scheme,
+            scheduled_for: 0,
+            cancelled: false,
+            restock: false,
+            fulfilled_at: None,
+            turnaround_secs: None,
+            supplier_id: 0,
+            condition_hash: BytesN::from_array(&env, &[0; 32]),
+            is_conditional: false,
+        };
+        let order_id = order.id;
+        storage::push_order(&env, discharge_plan_id, order);
+
+        let from_stage = plan.status;
+        plan.status = STAGE_ORDERS_PLACED;
+        storage::set_plan(&env, &plan);
+        storage::push_event_log(&env, discharge_plan_id, EVENT_ORDER_CREATED);
+        notify_subscribers(&env, discharge_plan_id, EVENT_ORDER_CREATED);
+        if from_stage != STAGE_ORDERS_PLACED {
+            emit_stage_changed(
+                &env,
+                discharge_plan_id,
+                from_stage,
+                STAGE_ORDERS_PLACED,
+                caller.clone(),
+            );
+        }
+
+        env.events()
+            .publish((TOPIC_ORDER, caller), (discharge_plan_id, order_id));
+
+        Ok(order_id)
+    }
+
+    /// Marks every order on the plan that has no status yet as
+    /// `default_status` (e.g. fulfilled or cancelled), so coordinators can
+    /// close out a discharge in one step rather than resolving each order
+    /// individually.
+    pub fn finalize_open_orders(
+        env: Env,
+        caller: Address,
+        discharge_plan_id: u64,
+        default_status: u32,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        storage::get_plan(&env, discharge_plan_id)?;
+
+        let mut list = storage::orders(&env, discharge_plan_id);
+        for i in 0..list.len() {
+            let mut order = list.get(i).unwrap();
+            if order.status.is_none() {
+                order.status = Some(default_status);
+                list.set(i, order);
+            }
+        }
+        storage::set_orders(&env, discharge_plan_id, &list);
+
+        Ok(())
+    }
+
+    pub fn get_discharge_orders(env: Env, discharge_plan_id: u64) -> OrderList {
+        storage::orders(&env, discharge_plan_id)
+    }
+
+    /// Updates a durable-medical-equipment order's equipment type and
+    /// delivery date before it ships, rejecting with
+    /// `Error::AlreadyDelivered` once the order has any status set (i.e.
+    /// `finalize_open_orders` or an equivalent has closed it out).
+    pub fn modify_dme_order(
+        env: Env,
+        caller: Address,
+        discharge_plan_id: u64,
+        order_id: u64,
+        new_equipment_type: u32,
+        new_delivery_date: u64,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        storage::get_plan(&env, discharge_plan_id)?;
+
+        let mut list = storage::orders(&env, discharge_plan_id);
+        for i in 0..list.len() {
+            let mut order = list.get(i).unwrap();
+            if order.id == order_id {
+                if order.status.is_some() {
+                    return Err(Error::AlreadyDelivered);
+                }
+                order.order_type = new_equipment_type;
+                order.scheduled_for = new_delivery_date;
+                list.set(i, order);
+                break;
+            }
+        }
+        storage::set_orders(&env, discharge_plan_id, &list);
+
+        Ok(())
+    }
+
+    /// Cancels a DME order before it's delivered, recording whether the
+    /// supplier should restock the equipment. Rejects with
+    /// `Error::AlreadyDelivered` if the order already has a status.
+    pub fn cancel_dme_order(
+        env: Env,
+        caller: Address,
+        discharge_plan_id: u64,
+        order_id: u64,
+        restock: bool,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        storage::get_plan(&env, discharge_plan_id)?;
+
+        let mut list = storage::orders(&env, discharge_plan_id);
+        for i in 0..list.len() {
+            let mut order = list.get(i).unwrap();
+            if order.id == order_id {
+                if order.status.is_some() {
+                    return Err(Error::AlreadyDelivered);
+                }
+                order.cancelled = true;
+                order.restock = restock;
+                list.set(i, order);
+                break;
+            }
+        }
+        storage::set_orders(&env, discharge_plan_id, &list);
+
+        env.events()
+            .publish((TOPIC_CANCEL, caller), (discharge_plan_id, order_id, restock));
+
+        Ok(())
+    }
+
+    /// Marks an order fulfilled and records its turnaround time
+    /// (`fulfilled_at - created_at`) for `get_average_order_turnaround`.
+    /// Rejects with `Error::AlreadyDelivered` if the order was cancelled or
+    /// already fulfilled.
+    pub fn mark_order_fulfilled(
+        env: Env,
+        caller: Address,
+        discharge_plan_id: u64,
+        order_id: u64,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        storage::get_plan(&env, discharge_plan_id)?;
+
+        let mut list = storage::orders(&env, discharge_plan_id);
+        for i in 0..list.len() {
+            let mut order = list.get(i).unwrap();
+            if order.id == order_id {
+                if order.cancelled || order.fulfilled_at.is_some() {
+                    return Err(Error::AlreadyDelivered);
+                }
+                let fulfilled_at = env.ledger().timestamp();
+                order.fulfilled_at = Some(fulfilled_at);
+                order.turnaround_secs = Some(fulfilled_at.saturating_sub(order.created_at));
+                list.set(i, order);
+                break;
+            }
+        }
+        storage::set_orders(&env, discharge_plan_id, &list);
+
+        Ok(())
+    }
+
+    /// Average `turnaround_secs` across a plan's fulfilled orders, `0` if
+    /// none have been marked fulfilled yet.
+    pub fn get_average_order_turnaround(
+        env: Env,
+        discharge_plan_id: u64,
+    ) -> Result<u64, Error> {
+        storage::get_plan(&env, discharge_plan_id)?;
+
+        let mut total = 0u64;
+        let mut count = 0u64;
+        for order in storage::orders(&env, discharge_plan_id).iter() {
+            if let Some(turnaround) = order.turnaround_secs {
+                total += turnaround;
+                count += 1;
+            }
+        }
+
+        Ok(total.checked_div(count).unwrap_or(0))
+    }
+
+    /// Registers the unit cost of a DME equipment type (the same code space
+    /// as `DischargeOrder::order_type`/`modify_dme_order`'s
+    /// `new_equipment_type`). Admin-only.
+    pub fn register_dme_cost(env: Env, admin: Address, equipment_type: u32, cost: u64) -> Result<(), Error> {
+        storage::require_admin(&env, &admin)?;
+        storage::set_dme_cost(&env, equipment_type, cost);
+        Ok(())
+    }
+
+    /// Sums the registered cost of each order on the plan whose type has a
+    /// registered DME cost, for cost-of-care estimates. Orders with no
+    /// registered cost contribute nothing rather than failing the call.
+    pub fn get_estimated_dme_cost(env: Env, discharge_plan_id: u64) -> Result<u64, Error> {
+        storage::get_plan(&env, discharge_plan_id)?;
+        let total = storage::orders(&env, discharge_plan_id)
+            .iter()
+            .filter_map(|order| storage::dme_cost(&env, order.order_type))
+            .sum();
+        Ok(total)
+    }
+
+    /// Addresses a DME order to a supplier, for
+    /// `get_supplier_pending_deliveries`.
+    pub fn set_order_supplier(
+        env: Env,
+        caller: Address,
+        discharge_plan_id: u64,
+        order_id: u64,
+        supplier_id: u64,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        storage::get_plan(&env, discharge_plan_id)?;
+
+        let mut list = storage::orders(&env, discharge_plan_id);
+        for i in 0..list.len() {
+            let mut order = list.get(i).unwrap();
+            if order.id == order_id {
+                order.supplier_id = supplier_id;
+                list.set(i, order);
+                break;
+            }
+        }
+        storage::set_orders(&env, discharge_plan_id, &list);
+
+        Ok(())
+    }
+
+    /// Marks an order as conditional on an off-chain rule referenced by
+    /// `condition_hash` (e.g. "insulin if glucose > 200"). Silent no-op if
+    /// `order_id` isn't found.
+    pub fn set_order_condition(
+        env: Env,
+        caller: Address,
+        discharge_plan_id: u64,
+        order_id: u64,
+        condition_hash: BytesN<32>,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        storage::get_plan(&env, discharge_plan_id)?;
+
+        let mut list = storage::orders(&env, discharge_plan_id);
+        for i in 0..list.len() {
+            let mut order = list.get(i).unwrap();
+            if order.id == order_id {
+                order.condition_hash = condition_hash;
+                order.is_conditional = true;
+                list.set(i, order);
+                break;
+            }
+        }
+        storage::set_orders(&env, discharge_plan_id, &list);
+
+        Ok(())
+    }
+
+    /// Returns `(is_conditional, condition_hash)` for an order, so a
+    /// caller can confirm whether an order is gated and retrieve the
+    /// off-chain rule it references.
+    pub fn get_order_condition(
+        env: Env,
+        discharge_plan_id: u64,
+        order_id: u64,
+    ) -> Result<(bool, BytesN<32>), Error> {
+        for order in storage::orders(&env, discharge_plan_id).iter() {
+            if order.id == order_id {
+                return Ok((order.is_conditional, order.condition_hash));
+            }
+        }
+        Err(Error::PlanNotFound)
+    }
+
+    /// Replaces an order's `details_hash` with `new_details_hash`, e.g. to
+    /// correct a document error, preserving the original hash in the
+    /// plan's amendment log (`get_order_amendments`). Silent no-op if
+    /// `order_id` isn't found.
+    pub fn amend_discharge_order(
+        env: Env,
+        caller: Address,
+        discharge_plan_id: u64,
+        order_id: u64,
+        new_details_hash: BytesN<32>,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        storage::get_plan(&env, discharge_plan_id)?;
+
+        let mut list = storage::orders(&env, discharge_plan_id);
+        for i in 0..list.len() {
+            let mut order = list.get(i).unwrap();
+            if order.id == order_id {
+                let previous_hash = order.details_hash.clone();
+                order.details_hash = new_details_hash.clone();
+                list.set(i, order);
+                storage::push_order_amendment_log(
+                    &env,
+                    discharge_plan_id,
+                    order_id,
+                    previous_hash,
+                    new_details_hash,
+                    caller.clone(),
+                );
+                break;
+            }
+        }
+        storage::set_orders(&env, discharge_plan_id, &list);
+
+        env.events()
+            .publish((TOPIC_AMEND, caller), (discharge_plan_id, order_id));
+
+        Ok(())
+    }
+
+    /// Append-only log of every `amend_discharge_order` call against this
+    /// plan, each entry carrying both the superseded and replacement hash.
+    pub fn get_order_amendments(env: Env, discharge_plan_id: u64) -> OrderAmendmentLog {
+        storage::order_amendment_log(&env, discharge_plan_id)
+    }
+
+    /// Filters a plan's orders down to the ones `actor` acted on, so a
+    /// provider can see only what they created without the caller
+    /// re-filtering `get_discharge_orders` client-side.
+    pub fn get_orders_by_actor(
+        env: Env,
+        discharge_plan_id: u64,
+        actor: Address,
+    ) -> Result<Vec<DischargeOrder>, Error> {
+        storage::get_plan(&env, discharge_plan_id)?;
+
+        let mut matching = Vec::new(&env);
+        for order in storage::orders(&env, discharge_plan_id).iter() {
+            if order.acted_by == actor {
+                matching.push_back(order);
+            }
+        }
+        Ok(matching)
+    }
+
+    /// Cross-plan worklist for a DME supplier: `(plan_id, equipment_type,
+    /// delivery_date)` for every one of their orders across `plan_ids`
+    /// that isn't cancelled or fulfilled yet. Unknown plan ids are skipped
+    /// rather than failing the whole call.
+    pub fn get_supplier_pending_deliveries(
+        env: Env,
+        supplier_id: u64,
+        plan_ids: Vec<u64>,
+    ) -> Vec<(u64, u32, u64)> {
+        let mut pending = Vec::new(&env);
+        for plan_id in plan_ids.iter() {
+            if storage::get_plan(&env, plan_id).is_err() {
+                continue;
+            }
+            for order in storage::orders(&env, plan_id).iter() {
+                if order.supplier_id == supplier_id
+                    && !order.cancelled
+                    && order.fulfilled_at.is_none()
+                {
+                    pending.push_back((plan_id, order.order_type, order.scheduled_for));
+                }
+            }
+        }
+        pending
+    }
+
+    /// True only if the plan has at least one order for each of the given
+    /// medically-required order types.
+    pub fn is_order_set_complete(
+        env: Env,
+        discharge_plan_id: u64,
+        required_types: Vec<u32>,
+    ) -> Result<bool, Error> {
+        storage::get_plan(&env, discharge_plan_id)?;
+        let existing = storage::orders(&env, discharge_plan_id);
+
+        for required_type in required_types.iter() {
+            if !existing.iter().any(|order| order.order_type == required_type) {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    pub fn complete_discharge(
+        env: Env,
+        caller: Address,
+        discharge_plan_id: u64,
+        actual_discharge_date: u64,
+        discharge_summary_hash: BytesN<32>,
+    ) -> Result<(), Error> {
+        let empty_cid = Bytes::new(&env);
+        Self::complete_discharge_with_cid(
+            env,
+            caller,
+            discharge_plan_id,
+            actual_discharge_date,
+            discharge_summary_hash,
+            empty_cid,
+        )
+    }
+
+    /// Like `complete_discharge`, but also anchors an off-chain IPFS CID
+    /// for the discharge summary alongside its hash. `summary_cid` may be
+    /// empty when the caller has none to record.
+    pub fn complete_discharge_with_cid(
+        env: Env,
+        caller: Address,
+        discharge_plan_id: u64,
+        actual_discharge_date: u64,
+        discharge_summary_hash: BytesN<32>,
+        summary_cid: Bytes,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        let mut plan = storage::get_plan(&env, discharge_plan_id)?;
+
+        if plan.status == STAGE_COMPLETED {
+            return Err(Error::AlreadyCompleted);
+        }
+        require_admitted(&env, &plan)?;
+        if actual_discharge_date < plan.admission_date {
+            return Err(Error::NotYetAdmitted);
+        }
+        if is_zero_hash(&discharge_summary_hash) {
+            return Err(Error::MissingSummary);
+        }
+        if plan.require_followup_before_dc
+            && storage::appointments(&env, discharge_plan_id).is_empty()
+        {
+            return Err(Error::NoFollowupScheduled);
+        }
+        if plan.require_patient_consent
+            && !storage::consent_record(&env, discharge_plan_id)
+                .map(|record| record.consented)
+                .unwrap_or(false)
+        {
+            return Err(Error::ConsentMissing);
+        }
+        if plan.require_barriers_resolved
+            && storage::barriers(&env, discharge_plan_id)
+                .iter()
+                .any(|barrier| !barrier.resolved)
+        {
+            return Err(Error::OpenBarriersRemain);
+        }
+        let mandatory = storage::mandatory_orders(&env, plan.destination);
+        if !mandatory.is_empty() {
+            let existing = storage::orders(&env, discharge_plan_id);
+            let all_present = mandatory
+                .iter()
+                .all(|required_type| existing.iter().any(|order| order.order_type == required_type));
+            if !all_present {
+                return Err(Error::MandatoryOrdersMissing);
+            }
+        }
+
+        let hours_ready_to_discharge = storage::readiness_history(&env, discharge_plan_id)
+            .iter()
+            .filter(|assessment| assessment.is_ready)
+            .last()
+            .map(|assessment| actual_discharge_date.saturating_sub(assessment.assessed_at) / 3600);
+
+        let threshold_at_completion = effective_readiness_threshold(&env, &plan);
+
+        let from_stage = plan.status;
+        plan.actual_discharge_date = actual_discharge_date;
+        plan.status = STAGE_COMPLETED;
+        storage::set_plan(&env, &plan);
+        storage::set_completion_details(
+            &env,
+            discharge_plan_id,
+            &CompletionDetails {
+                discharge_summary_hash,
+                completed_at: env.ledger().timestamp(),
+                hours_ready_to_discharge,
+                summary_cid,
+                threshold_at_completion,
+            },
+        );
+        storage::push_event_log(&env, discharge_plan_id, EVENT_COMPLETED);
+        notify_subscribers(&env, discharge_plan_id, EVENT_COMPLETED);
+        emit_stage_changed(
+            &env,
+            discharge_plan_id,
+            from_stage,
+            STAGE_COMPLETED,
+            caller.clone(),
+        );
+
+        env.events()
+            .publish((TOPIC_COMPLETE, caller), discharge_plan_id);
+
+        Ok(())
+    }
+
+    /// Completes a plan bypassing the procedural gates (`complete_discharge`
+    /// would reject on) `require_followup_before_dc`,
+    /// `require_patient_consent`, `require_barriers_resolved`, and
+    /// mandatory orders — for mass-discharge emergencies where those
+    /// formalities can't be completed first. Still requires a non-empty
+    /// summary hash and an un-completed plan. Admin-only; recorded to
+    /// `get_override_log` alongside `justification_code` for audit.
+    pub fn complete_discharge_expedited(
+        env: Env,
+        admin: Address,
+        discharge_plan_id: u64,
+        actual_discharge_date: u64,
+        discharge_summary_hash: BytesN<32>,
+        justification_code: u32,
+    ) -> Result<(), Error> {
+        storage::require_admin(&env, &admin)?;
+        let mut plan = storage::get_plan(&env, discharge_plan_id)?;
+
+        if plan.status == STAGE_COMPLETED {
+            return Err(Error::AlreadyCompleted);
+        }
+        if is_zero_hash(&discharge_summary_hash) {
+            return Err(Error::MissingSummary);
+        }
+
+        let hours_ready_to_discharge = storage::readiness_history(&env, discharge_plan_id)
+            .iter()
+            .filter(|assessment| assessment.is_ready)
+            .last()
+            .map(|assessment| actual_discharge_date.saturating_sub(assessment.assessed_at) / 3600);
+        let threshold_at_completion = effective_readiness_threshold(&env, &plan);
+
+        let from_stage = plan.status;
+        plan.actual_discharge_date = actual_discharge_date;
+        plan.status = STAGE_COMPLETED;
+        storage::set_plan(&env, &plan);
+        storage::set_completion_details(
+            &env,
+            discharge_plan_id,
+            &CompletionDetails {
+                discharge_summary_hash,
+                completed_at: env.ledger().timestamp(),
+                hours_ready_to_discharge,
+                summary_cid: Bytes::new(&env),
+                threshold_at_completion,
+            },
+        );
+        storage::push_event_log(&env, discharge_plan_id, EVENT_COMPLETED);
+        notify_subscribers(&env, discharge_plan_id, EVENT_COMPLETED);
+        storage::push_override_log(
+            &env,
+            discharge_plan_id,
+            OVERRIDE_TYPE_EXPEDITED_COMPLETION,
+            admin.clone(),
+        );
+        emit_stage_changed(
+            &env,
+            discharge_plan_id,
+            from_stage,
+            STAGE_COMPLETED,
+            admin.clone(),
+        );
+
+        env.events().publish(
+            (TOPIC_COMPLETE, admin),
+            (discharge_plan_id, justification_code),
+        );
+
+        Ok(())
+    }
+
+    /// Every override recorded against this plan — a readiness override, an
+    /// expedited completion, or an emergency read — as
+    /// `(override_type, actor, timestamp)` triples in the order they
+    /// occurred.
+    pub fn get_override_log(env: Env, discharge_plan_id: u64) -> Vec<(u32, Address, u64)> {
+        let mut triples = Vec::new(&env);
+        for entry in storage::override_log(&env, discharge_plan_id).iter() {
+            triples.push_back((entry.override_type, entry.actor, entry.timestamp));
+        }
+        triples
+    }
+
+    /// The IPFS CID recorded alongside the discharge summary hash, if any
+    /// (see `complete_discharge_with_cid`).
+    pub fn get_completion_cid(env: Env, discharge_plan_id: u64) -> Result<Bytes, Error> {
+        Ok(storage::completion_details(&env, discharge_plan_id)
+            .ok_or(Error::PlanNotFound)?
+            .summary_cid)
+    }
+
+    /// The readiness threshold frozen at the moment of completion, immune
+    /// to later global/destination/per-plan threshold changes.
+    pub fn get_threshold_at_completion(env: Env, discharge_plan_id: u64) -> Result<u32, Error> {
+        Ok(storage::completion_details(&env, discharge_plan_id)
+            .ok_or(Error::PlanNotFound)?
+            .threshold_at_completion)
+    }
+
+    /// Lets an off-chain verifier confirm a candidate document hash matches
+    /// the one anchored at completion. `Error::PlanNotFound` if the plan
+    /// doesn't exist, `Error::NotCompleted` if it hasn't completed yet.
+    pub fn verify_summary_hash(
+        env: Env,
+        discharge_plan_id: u64,
+        candidate: BytesN<32>,
+    ) -> Result<bool, Error> {
+        storage::get_plan(&env, discharge_plan_id)?;
+        let details = storage::completion_details(&env, discharge_plan_id)
+            .ok_or(Error::NotCompleted)?;
+        Ok(details.discharge_summary_hash == candidate)
+    }
+
+    /// Maps a `Destination` code to a short human-readable symbol, so
+    /// clients don't each re-implement the 0-3 enum. `Error::InvalidInput`
+    /// for anything outside `DESTINATION_HOME..=DESTINATION_OTHER`.
+    pub fn get_destination_label(_env: Env, destination: u32) -> Result<Symbol, Error> {
+        match destination {
+            DESTINATION_HOME => Ok(symbol_short!("home")),
+            DESTINATION_SNF => Ok(symbol_short!("snf")),
+            DESTINATION_REHAB => Ok(symbol_short!("rehab")),
+            DESTINATION_OTHER => Ok(symbol_short!("other")),
+            _ => Err(Error::InvalidInput),
+        }
+    }
+
+    /// Maps an `order_type` code to a short human-readable symbol.
+    /// `order_type == 0` is treated as an unlabeled/generic order type.
+    /// `Error::InvalidInput` for any code outside the known set.
+    pub fn get_order_type_label(_env: Env, order_type: u32) -> Result<Symbol, Error> {
+        match order_type {
+            0 => Ok(symbol_short!("generic")),
+            ORDER_TYPE_MEDICATION => Ok(symbol_short!("medic")),
+            ORDER_TYPE_DME => Ok(symbol_short!("dme")),
+            ORDER_TYPE_HOME_HEALTH => Ok(symbol_short!("homehlth")),
+            _ => Err(Error::InvalidInput),
+        }
+    }
+
+    /// Maps a follow-up appointment `specialty` code to a short
+    /// human-readable symbol. `Error::InvalidInput` for anything outside
+    /// `SPECIALTY_PRIMARY_CARE..=SPECIALTY_OTHER`.
+    pub fn get_specialty_label(_env: Env, specialty: u32) -> Result<Symbol, Error> {
+        match specialty {
+            SPECIALTY_PRIMARY_CARE => Ok(symbol_short!("pcp")),
+            SPECIALTY_CARDIOLOGY => Ok(symbol_short!("cards")),
+            SPECIALTY_SURGERY => Ok(symbol_short!("surgery")),
+            SPECIALTY_OTHER => Ok(symbol_short!("other")),
+            _ => Err(Error::InvalidInput),
+        }
+    }
+
+    /// Explicitly walks a completed plan's lifecycle stage back to
+    /// `STAGE_ORDERS_PLACED`, the only sanctioned way to make a completed
+    /// plan accept further clinical emits (readiness, orders). Without this,
+    /// `assess_discharge_readiness` and `create_dc_orders_with_scheme`
+    /// reject with `Error::InvalidStateTransition` once a plan is completed.
+    pub fn reopen_discharge_plan(env: Env, admin: Address, discharge_plan_id: u64) -> Result<(), Error> {
+        storage::require_admin(&env, &admin)?;
+        let mut plan = storage::get_plan(&env, discharge_plan_id)?;
+        if plan.status != STAGE_COMPLETED {
+            return Err(Error::InvalidStateTransition);
+        }
+        plan.status = STAGE_ORDERS_PLACED;
+        storage::set_plan(&env, &plan);
+        emit_stage_changed(
+            &env,
+            discharge_plan_id,
+            STAGE_COMPLETED,
+            STAGE_ORDERS_PLACED,
+            admin,
+        );
+        Ok(())
+    }
+
+    /// Completes many plans in one call for mass-discharge events (e.g. an
+    /// evacuation). Each item is attempted independently via
+    /// `complete_discharge`; a nonexistent or already-completed plan is
+    /// reported as `false` rather than failing the whole batch.
+    pub fn complete_discharge_batch(
+        env: Env,
+        caller: Address,
+        items: Vec<(u64, u64, BytesN<32>)>,
+    ) -> Vec<(u64, bool)> {
+        let mut results = Vec::new(&env);
+        for (discharge_plan_id, actual_discharge_date, discharge_summary_hash) in items.iter() {
+            let success = Self::complete_discharge(
+                env.clone(),
+                caller.clone(),
+                discharge_plan_id,
+                actual_discharge_date,
+                discharge_summary_hash,
+            )
+            .is_ok();
+            results.push_back((discharge_plan_id, success));
+        }
+        results
+    }
+
+    /// Adds an open barrier to discharge (e.g. no ride home, no SNF bed
+    /// available).
+    pub fn add_discharge_barrier(
+        env: Env,
+        caller: Address,
+        discharge_plan_id: u64,
+        barrier_code: u32,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        storage::get_plan(&env, discharge_plan_id)?;
+
+        let mut list = storage::barriers(&env, discharge_plan_id);
+        list.push_back(DischargeBarrier {
+            barrier_code,
+            added_at: env.ledger().timestamp(),
+            resolved: false,
+        });
+        storage::set_barriers(&env, discharge_plan_id, &list);
+        Ok(())
+    }
+
+    pub fn resolve_discharge_barrier(
+        env: Env,
+        caller: Address,
+        discharge_plan_id: u64,
+        barrier_code: u32,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        storage::get_plan(&env, discharge_plan_id)?;
+
+        let mut list = storage::barriers(&env, discharge_plan_id);
+        for i in 0..list.len() {
+            let mut barrier = list.get(i).unwrap();
+            if barrier.barrier_code == barrier_code && !barrier.resolved {
+                barrier.resolved = true;
+                list.set(i, barrier);
+                break;
+            }
+        }
+        storage::set_barriers(&env, discharge_plan_id, &list);
+        Ok(())
+    }
+
+    pub fn get_open_barriers(env: Env, discharge_plan_id: u64) -> DischargeBarriers {
+        let mut open = DischargeBarriers::new(&env);
+        for barrier in storage::barriers(&env, discharge_plan_id).iter() {
+            if !barrier.resolved {
+                open.push_back(barrier);
+            }
+        }
+        open
+    }
+
+    pub fn set_require_barriers_resolved(
+        env: Env,
+        caller: Address,
+        discharge_plan_id: u64,
+        required: bool,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        let mut plan = storage::get_plan(&env, discharge_plan_id)?;
+        plan.require_barriers_resolved = required;
+        storage::set_plan(&env, &plan);
+        Ok(())
+    }
+
+    /// Records the patient's (or their representative's) consent status
+    /// for the discharge plan, for HIPAA/consent audit purposes.
+    pub fn record_patient_consent(
+        env: Env,
+        caller: Address,
+        discharge_plan_id: u64,
+        consent_hash: BytesN<32>,
+        consented: bool,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        storage::get_plan(&env, discharge_plan_id)?;
+
+        storage::set_consent_record(
+            &env,
+            discharge_plan_id,
+            &ConsentRecord {
+                consent_hash,
+                consented,
+                recorded_at: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    pub fn set_require_patient_consent(
+        env: Env,
+        caller: Address,
+        discharge_plan_id: u64,
+        required: bool,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        let mut plan = storage::get_plan(&env, discharge_plan_id)?;
+        plan.require_patient_consent = required;
+        storage::set_plan(&env, &plan);
+        Ok(())
+    }
+
+    /// Links `patient` to `patient_id` and records the hash of their access
+    /// proof, so that `patient_read_own_plan` can later verify a caller
+    /// without trusting a bare id match. Admin-only.
+    pub fn register_patient_access(
+        env: Env,
+        admin: Address,
+        patient_id: u64,
+        patient: Address,
+        proof_hash: BytesN<32>,
+    ) -> Result<(), Error> {
+        storage::require_admin(&env, &admin)?;
+        storage::set_patient_access(&env, patient_id, &patient, &proof_hash);
+        Ok(())
+    }
+
+    /// A patient's scoped read of their own plan: `caller` must be the
+    /// address registered via `register_patient_access` for the plan's
+    /// `patient_id`, and `patient_proof` must match the hash recorded for
+    /// them, else `Error::NotAuthorized`.
+    pub fn patient_read_own_plan(
+        env: Env,
+        caller: Address,
+        discharge_plan_id: u64,
+        patient_proof: BytesN<32>,
+    ) -> Result<DischargeSummary, Error> {
+        caller.require_auth();
+        let plan = storage::get_plan(&env, discharge_plan_id)?;
+
+        let (registered_patient, expected_proof) =
+            storage::patient_access(&env, plan.patient_id).ok_or(Error::NotAuthorized)?;
+        if registered_patient != caller || expected_proof != patient_proof {
+            return Err(Error::NotAuthorized);
+        }
+
+        Ok(DischargeSummary {
+            discharge_plan_id,
+            status: plan.status,
+            destination: plan.destination,
+            expected_discharge_date: plan.expected_discharge_date,
+            actual_discharge_date: plan.actual_discharge_date,
+            is_ready: storage::readiness_history(&env, discharge_plan_id)
+                .last()
+                .map(|latest| latest.is_ready)
+                .unwrap_or(false),
+        })
+    }
+
+    /// Records SNF transfer coordination for a plan. `transfer_date` must
+    /// be in the future; when `strict` is set it must also fall on or after
+    /// the plan's `expected_discharge_date`, catching a transfer scheduled
+    /// before the patient is even expected to be ready to leave.
+    pub fn coordinate_with_snf(
+        env: Env,
+        caller: Address,
+        discharge_plan_id: u64,
+        facility_id: u64,
+        transfer_date: u64,
+        strict: bool,
+        allow_overwrite: bool,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        let plan = storage::get_plan(&env, discharge_plan_id)?;
+
+        if storage::snf_coordination(&env, discharge_plan_id).is_some() && !allow_overwrite {
+            return Err(Error::CoordinationExists);
+        }
+        if transfer_date <= env.ledger().timestamp() {
+            return Err(Error::InvalidDate);
+        }
+        if strict && transfer_date < plan.expected_discharge_date {
+            return Err(Error::InvalidDate);
+        }
+        if plan.require_authorization
+            && !storage::authorization(&env, discharge_plan_id, SERVICE_TYPE_SNF)
+                .map(|record| record.authorized)
+                .unwrap_or(false)
+        {
+            return Err(Error::AuthorizationRequired);
+        }
+
+        storage::set_snf_coordination(
+            &env,
+            discharge_plan_id,
+            &SnfCoordination {
+                facility_id,
+                transfer_date,
+            },
+        );
+        Ok(())
+    }
+
+    pub fn get_snf_coordination(env: Env, discharge_plan_id: u64) -> Option<SnfCoordination> {
+        storage::snf_coordination(&env, discharge_plan_id)
+    }
+
+    /// Lets a physician grant a delegate (e.g. a covering nurse) a bitmask
+    /// of permissions (`storage::PERMISSION_*`) scoped to one plan, without
+    /// handing out a global admin/provider role. Restricted to addresses
+    /// registered via `register_physician`.
+    pub fn delegate_plan_access(
+        env: Env,
+        caller: Address,
+        discharge_plan_id: u64,
+        delegate: Address,
+        permissions: u32,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        if !storage::is_physician(&env, &caller) {
+            return Err(Error::NotAuthorized);
+        }
+        storage::get_plan(&env, discharge_plan_id)?;
+        storage::set_delegate_permissions(&env, discharge_plan_id, &delegate, permissions);
+        Ok(())
+    }
+
+    pub fn revoke_plan_access(
+        env: Env,
+        caller: Address,
+        discharge_plan_id: u64,
+        delegate: Address,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        if !storage::is_physician(&env, &caller) {
+            return Err(Error::NotAuthorized);
+        }
+        storage::get_plan(&env, discharge_plan_id)?;
+        storage::clear_delegate_permissions(&env, discharge_plan_id, &delegate);
+        Ok(())
+    }
+
+    /// Same as `assess_discharge_readiness`, but for a delegate acting under
+    /// `delegate_plan_access` rather than the plan's own staff; requires
+    /// `storage::PERMISSION_READINESS`.
+    pub fn assess_dc_readiness_as_delegate(
+        env: Env,
+        delegate: Address,
+        discharge_plan_id: u64,
+        sub_scores: Vec<u32>,
+    ) -> Result<u32, Error> {
+        delegate.require_auth();
+        if !storage::can_act_on_plan(&env, discharge_plan_id, &delegate, storage::PERMISSION_READINESS) {
+            return Err(Error::NotAuthorized);
+        }
+        let total = sub_scores.iter().sum::<u32>() / sub_scores.len();
+        Self::record_readiness(&env, delegate, discharge_plan_id, sub_scores, total)
+    }
+
+    /// Audit query: of the given plan ids, which are completed with a
+    /// zero `discharge_summary_hash` (i.e. completed before validation was
+    /// enforced, or via a path that bypassed `complete_discharge`).
+    pub fn get_plans_missing_summary(env: Env, ids: Vec<u64>) -> Vec<u64> {
+        let mut missing = Vec::new(&env);
+        for plan_id in ids.iter() {
+            let Ok(plan) = storage::get_plan(&env, plan_id) else {
+                continue;
+            };
+            if plan.status != STAGE_COMPLETED {
+                continue;
+            }
+            let is_zero = storage::completion_details(&env, plan_id)
+                .map(|details| is_zero_hash(&details.discharge_summary_hash))
+                .unwrap_or(true);
+            if is_zero {
+                missing.push_back(plan_id);
+            }
+        }
+        missing
+    }
+
+    /// Batch status read for census boards: `(id, status_code)` pairs for
+    /// each known id, skipping unknown ones. Accepts at most
+    /// `FULL_EXPORT_MAX_ITEMS` ids, ignoring any beyond that.
+    pub fn get_plan_statuses(env: Env, ids: Vec<u64>) -> Vec<(u64, u32)> {
+        let mut statuses = Vec::new(&env);
+        for i in 0..ids.len().min(FULL_EXPORT_MAX_ITEMS) {
+            let plan_id = ids.get(i).unwrap();
+            let Ok(plan) = storage::get_plan(&env, plan_id) else {
+                continue;
+            };
+            statuses.push_back((plan_id, plan.status));
+        }
+        statuses
+    }
+
+    pub fn get_completion_details(
+        env: Env,
+        discharge_plan_id: u64,
+    ) -> Result<CompletionDetails, Error> {
+        storage::completion_details(&env, discharge_plan_id).ok_or(Error::PlanNotFound)
+    }
+
+    /// Marks a plan's patient as readmitted and bumps their lifetime
+    /// readmission count, which `estimate_readmission_probability` draws on
+    /// for future admissions.
+    pub fn record_readmission(
+        env: Env,
+        caller: Address,
+        discharge_plan_id: u64,
+    ) -> Result<u32, Error> {
+        caller.require_auth();
+        let mut plan = storage::get_plan(&env, discharge_plan_id)?;
+        plan.readmitted = true;
+        storage::set_plan(&env, &plan);
+
+        Ok(storage::increment_readmission_count(&env, plan.patient_id))
+    }
+
+    /// Pulls the plan's medication orders for reconciliation against a
+    /// recorded readmission (see `record_readmission`), to support
+    /// medication-error analysis. Fails with `Error::NotReadmitted` if the
+    /// plan was never marked readmitted.
+    pub fn get_prior_meds_for_readmission(
+        env: Env,
+        discharge_plan_id: u64,
+    ) -> Result<OrderList, Error> {
+        let plan = storage::get_plan(&env, discharge_plan_id)?;
+        if !plan.readmitted {
+            return Err(Error::NotReadmitted);
+        }
+
+        let mut medications = Vec::new(&env);
+        for order in storage::orders(&env, discharge_plan_id).iter() {
+            if order.order_type == ORDER_TYPE_MEDICATION {
+                medications.push_back(order);
+            }
+        }
+        Ok(medications)
+    }
+
+    /// Blends a patient's prior readmission frequency with the caller-supplied
+    /// `current_factors` bitmask (each set bit a present risk factor) into a
+    /// 0-100 probability: 15 points per prior readmission plus 10 points per
+    /// set bit in `current_factors`, capped at 100.
+    pub fn estimate_readmission_probability(
+        env: Env,
+        patient_id: u64,
+        current_factors: u32,
+    ) -> u32 {
+        let prior = storage::readmission_count(&env, patient_id) * 15;
+        let current = current_factors.count_ones() * 10;
+        (prior + current).min(100)
+    }
+
+    /// Percentage of completed plans among `ids` that were marked
+    /// readmitted (see `record_readmission`). Incomplete plans and unknown
+    /// ids are skipped from both the numerator and denominator; returns 0
+    /// if no completed plans are found.
+    pub fn get_readmission_rate(env: Env, ids: Vec<u64>) -> u32 {
+        let mut completed = 0u32;
+        let mut readmitted = 0u32;
+        for plan_id in ids.iter() {
+            let Ok(plan) = storage::get_plan(&env, plan_id) else {
+                continue;
+            };
+            if plan.status != STAGE_COMPLETED {
+                continue;
+            }
+            completed += 1;
+            if plan.readmitted {
+                readmitted += 1;
+            }
+        }
+        if completed == 0 {
+            return 0;
+        }
+        readmitted * 100 / completed
+    }
+
+    /// Single-fetch bundle of everything known about a plan, for
+    /// offline-capable mobile clients. See `FullPlanExport` for the
+    /// per-collection truncation this applies.
+    pub fn get_full_plan_export(env: Env, discharge_plan_id: u64) -> Result<FullPlanExport, Error> {
+        let plan = storage::get_plan(&env, discharge_plan_id)?;
+
+        Ok(FullPlanExport {
+            plan,
+            readiness_history: truncate(&env, &storage::readiness_history(&env, discharge_plan_id)),
+            orders: truncate(&env, &storage::orders(&env, discharge_plan_id)),
+            home_health: storage::home_health_arrangement(&env, discharge_plan_id),
+            appointments: truncate(&env, &storage::appointments(&env, discharge_plan_id)),
+            education_records: truncate(&env, &storage::education_records(&env, discharge_plan_id)),
+            risk_history: truncate(&env, &storage::risk_history(&env, discharge_plan_id)),
+        })
+    }
+
+    /// Takes an immutable, admin-only point-in-time capture of a plan's full
+    /// state for audit purposes. Unlike `get_full_plan_export`, the result
+    /// is persisted under the returned id and never changes afterward, even
+    /// if the plan itself is later amended.
+    pub fn create_plan_snapshot(
+        env: Env,
+        admin: Address,
+        discharge_plan_id: u64,
+    ) -> Result<u64, Error> {
+        storage::require_admin(&env, &admin)?;
+        let export = Self::get_full_plan_export(env.clone(), discharge_plan_id)?;
+
+        let snapshot_id = storage::get_and_increment_snapshot_counter(&env)?;
+        storage::set_snapshot(
+            &env,
+            snapshot_id,
+            &PlanSnapshot {
+                discharge_plan_id,
+                taken_at: env.ledger().timestamp(),
+                export,
+            },
+        );
+
+        Ok(snapshot_id)
+    }
+
+    pub fn get_plan_snapshot(env: Env, snapshot_id: u64) -> Option<PlanSnapshot> {
+        storage::get_snapshot(&env, snapshot_id)
+    }
+
+    /// Cross-checks the plan's `EventLog` against the structures it implies
+    /// should exist (readiness history present iff a readiness event was
+    /// logged, at least one order iff an order event was logged, and so on),
+    /// returning `false` on any mismatch. This catches storage corruption
+    /// that a simple existence check on the plan itself would miss.
+    pub fn verify_plan_integrity(env: Env, discharge_plan_id: u64) -> bool {
+        if storage::get_plan(&env, discharge_plan_id).is_err() {
+            return false;
+        }
+
+        let log = storage::event_log(&env, discharge_plan_id);
+        let logged = |code: u32| log.iter().any(|entry| entry.code == code);
+
+        let readiness_ok =
+            logged(EVENT_READINESS_ASSESSED) != storage::readiness_history(&env, discharge_plan_id).is_empty();
+        let orders_ok = logged(EVENT_ORDER_CREATED) != storage::orders(&env, discharge_plan_id).is_empty();
+        let completion_ok = logged(EVENT_COMPLETED)
+            == (storage::get_plan(&env, discharge_plan_id)
+                .map(|p| p.status == STAGE_COMPLETED)
+                .unwrap_or(false));
+
+        readiness_ok && orders_ok && completion_ok
+    }
+}
@@ -0,0 +1,842 @@
+use soroban_sdk::{contracttype, Address, BytesN, Env, Vec};
+
+use crate::errors::Error;
+
+use crate::types::{
+    Appointments, AuthorizationRecord, Caregiver, Caregivers, CompletionDetails, ConsentRecord,
+    DischargeBarriers, DischargeOrder, DischargePlan, DomainProgress, EducationRecord,
+    EducationRecords, EventLog, EventLogEntry, HomeHealthArrangement, OrderAmendmentEntry,
+    OrderAmendmentLog, OrderList, OverrideLog, OverrideLogEntry, PlanSnapshot, ReadinessAssessment,
+    ReadinessOverride, RiskRecord, SnfCoordination, Subscribers, VitalsCheck, VitalsChecks,
+    RISK_FACTOR_COUNT,
+};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    Admin,
+    PlanCounter,
+    AppointmentCounter,
+    MaxAppointmentsPerPlan,
+    Plan(u64),
+    ReadinessHistory(u64),
+    Orders(u64),
+    EventLog(u64),
+    Education(u64),
+    Appointments(u64),
+    HomeHealth(u64),
+    ReadinessPreset(u32),
+    RiskHistory(u64),
+    EscalatedIndex,
+    Caregivers(u64),
+    DomainProgress(u64),
+    DestinationIndex(u32),
+    Completion(u64),
+    ReadmissionCount(u64),
+    DrgIndex(u32),
+    PathwayIndex(u32),
+    Delegation(u64, Address),
+    SnfCoordination(u64),
+    Consent(u64),
+    ReadinessThreshold(u32),
+    ReadinessCosignRequired,
+    Barriers(u64),
+    GlobalReadinessThreshold,
+    PlanReadinessThreshold(u64),
+    VitalsChecks(u64),
+    AllowSchedulingAfterCompletion,
+    SnapshotCounter,
+    Snapshot(u64),
+    Subscribers(u64),
+    RequiredEducationTopics(u64),
+    MandatoryOrders(u32),
+    Physician(Address),
+    ReadinessOverride(u64),
+    DmeCost(u32),
+    PatientAccess(u64),
+    OverrideLog(u64),
+    ProviderIndex(u64),
+    PostDischargeSchedulingWindow,
+    PcpFollowupWindowDays,
+    Agency(BytesN<32>),
+    Supplier(BytesN<32>),
+    OrderAmendmentLog(u64),
+    Authorization(u64, u32),
+    RiskFactorWeights,
+    DiagnosisIndex(u32),
+}
+
+pub fn barriers(env: &Env, plan_id: u64) -> DischargeBarriers {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Barriers(plan_id))
+        .unwrap_or(DischargeBarriers::new(env))
+}
+
+pub fn set_barriers(env: &Env, plan_id: u64, list: &DischargeBarriers) {
+    env.storage().persistent().set(&DataKey::Barriers(plan_id), list);
+}
+
+pub fn readiness_cosign_required(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::ReadinessCosignRequired)
+        .unwrap_or(false)
+}
+
+pub fn set_readiness_cosign_required(env: &Env, required: bool) {
+    env.storage()
+        .instance()
+        .set(&DataKey::ReadinessCosignRequired, &required);
+}
+
+pub fn set_latest_readiness(env: &Env, plan_id: u64, assessment: ReadinessAssessment) {
+    let mut history = readiness_history(env, plan_id);
+    if !history.is_empty() {
+        let last = history.len() - 1;
+        history.set(last, assessment);
+        env.storage()
+            .persistent()
+            .set(&DataKey::ReadinessHistory(plan_id), &history);
+    }
+}
+
+pub fn readiness_threshold(env: &Env, destination: u32) -> Option<u32> {
+    env.storage()
+        .instance()
+        .get(&DataKey::ReadinessThreshold(destination))
+}
+
+pub fn set_readiness_threshold(env: &Env, destination: u32, threshold: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::ReadinessThreshold(destination), &threshold);
+}
+
+pub fn global_readiness_threshold(env: &Env) -> Option<u32> {
+    env.storage().instance().get(&DataKey::GlobalReadinessThreshold)
+}
+
+pub fn set_global_readiness_threshold(env: &Env, threshold: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::GlobalReadinessThreshold, &threshold);
+}
+
+pub fn plan_readiness_threshold(env: &Env, plan_id: u64) -> Option<u32> {
+    env.storage()
+        .instance()
+        .get(&DataKey::PlanReadinessThreshold(plan_id))
+}
+
+pub fn set_plan_readiness_threshold(env: &Env, plan_id: u64, threshold: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::PlanReadinessThreshold(plan_id), &threshold);
+}
+
+pub fn vitals_checks(env: &Env, plan_id: u64) -> VitalsChecks {
+    env.storage()
+        .persistent()
+        .get(&DataKey::VitalsChecks(plan_id))
+        .unwrap_or(VitalsChecks::new(env))
+}
+
+pub fn push_vitals_check(env: &Env, plan_id: u64, check: VitalsCheck) {
+    let mut checks = vitals_checks(env, plan_id);
+    checks.push_back(check);
+    env.storage()
+        .persistent()
+        .set(&DataKey::VitalsChecks(plan_id), &checks);
+}
+
+pub fn plan_subscribers(env: &Env, plan_id: u64) -> Subscribers {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Subscribers(plan_id))
+        .unwrap_or(Subscribers::new(env))
+}
+
+pub fn set_plan_subscribers(env: &Env, plan_id: u64, subscribers: &Subscribers) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Subscribers(plan_id), subscribers);
+}
+
+pub fn required_education_topics(env: &Env, plan_id: u64) -> Vec<u32> {
+    env.storage()
+        .instance()
+        .get(&DataKey::RequiredEducationTopics(plan_id))
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn set_required_education_topics(env: &Env, plan_id: u64, topics: &Vec<u32>) {
+    env.storage()
+        .instance()
+        .set(&DataKey::RequiredEducationTopics(plan_id), topics);
+}
+
+pub fn mandatory_orders(env: &Env, destination: u32) -> Vec<u32> {
+    env.storage()
+        .instance()
+        .get(&DataKey::MandatoryOrders(destination))
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn set_mandatory_orders(env: &Env, destination: u32, required_types: &Vec<u32>) {
+    env.storage()
+        .instance()
+        .set(&DataKey::MandatoryOrders(destination), required_types);
+}
+
+pub fn is_physician(env: &Env, address: &Address) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::Physician(address.clone()))
+        .unwrap_or(false)
+}
+
+pub fn set_physician(env: &Env, address: &Address, is_physician: bool) {
+    env.storage()
+        .instance()
+        .set(&DataKey::Physician(address.clone()), &is_physician);
+}
+
+pub fn is_registered_agency(env: &Env, agency_id: &BytesN<32>) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::Agency(agency_id.clone()))
+        .unwrap_or(false)
+}
+
+pub fn set_registered_agency(env: &Env, agency_id: &BytesN<32>, registered: bool) {
+    env.storage()
+        .instance()
+        .set(&DataKey::Agency(agency_id.clone()), &registered);
+}
+
+pub fn is_registered_supplier(env: &Env, supplier_id: &BytesN<32>) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::Supplier(supplier_id.clone()))
+        .unwrap_or(false)
+}
+
+pub fn set_registered_supplier(env: &Env, supplier_id: &BytesN<32>, registered: bool) {
+    env.storage()
+        .instance()
+        .set(&DataKey::Supplier(supplier_id.clone()), &registered);
+}
+
+pub fn readiness_override(env: &Env, plan_id: u64) -> Option<ReadinessOverride> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ReadinessOverride(plan_id))
+}
+
+pub fn set_readiness_override(env: &Env, plan_id: u64, readiness_override: &ReadinessOverride) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::ReadinessOverride(plan_id), readiness_override);
+}
+
+pub fn dme_cost(env: &Env, equipment_type: u32) -> Option<u64> {
+    env.storage().instance().get(&DataKey::DmeCost(equipment_type))
+}
+
+pub fn set_dme_cost(env: &Env, equipment_type: u32, cost: u64) {
+    env.storage()
+        .instance()
+        .set(&DataKey::DmeCost(equipment_type), &cost);
+}
+
+pub fn override_log(env: &Env, plan_id: u64) -> OverrideLog {
+    env.storage()
+        .persistent()
+        .get(&DataKey::OverrideLog(plan_id))
+        .unwrap_or(OverrideLog::new(env))
+}
+
+pub fn push_override_log(env: &Env, plan_id: u64, override_type: u32, actor: Address) {
+    let mut log = override_log(env, plan_id);
+    log.push_back(OverrideLogEntry {
+        override_type,
+        actor,
+        timestamp: env.ledger().timestamp(),
+    });
+    env.storage().persistent().set(&DataKey::OverrideLog(plan_id), &log);
+}
+
+pub fn order_amendment_log(env: &Env, plan_id: u64) -> OrderAmendmentLog {
+    env.storage()
+        .persistent()
+        .get(&DataKey::OrderAmendmentLog(plan_id))
+        .unwrap_or(OrderAmendmentLog::new(env))
+}
+
+pub fn push_order_amendment_log(
+    env: &Env,
+    plan_id: u64,
+    order_id: u64,
+    previous_hash: BytesN<32>,
+    new_hash: BytesN<32>,
+    amended_by: Address,
+) {
+    let mut log = order_amendment_log(env, plan_id);
+    log.push_back(OrderAmendmentEntry {
+        order_id,
+        previous_hash,
+        new_hash,
+        amended_by,
+        amended_at: env.ledger().timestamp(),
+    });
+    env.storage()
+        .persistent()
+        .set(&DataKey::OrderAmendmentLog(plan_id), &log);
+}
+
+pub fn authorization(env: &Env, plan_id: u64, service_type: u32) -> Option<AuthorizationRecord> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Authorization(plan_id, service_type))
+}
+
+pub fn set_authorization(env: &Env, plan_id: u64, service_type: u32, record: &AuthorizationRecord) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Authorization(plan_id, service_type), record);
+}
+
+pub fn patient_access(env: &Env, patient_id: u64) -> Option<(Address, BytesN<32>)> {
+    env.storage().instance().get(&DataKey::PatientAccess(patient_id))
+}
+
+pub fn set_patient_access(env: &Env, patient_id: u64, patient: &Address, proof_hash: &BytesN<32>) {
+    env.storage().instance().set(
+        &DataKey::PatientAccess(patient_id),
+        &(patient.clone(), proof_hash.clone()),
+    );
+}
+
+pub fn get_and_increment_snapshot_counter(env: &Env) -> Result<u64, Error> {
+    let current = env
+        .storage()
+        .instance()
+        .get::<_, u64>(&DataKey::SnapshotCounter)
+        .unwrap_or(0);
+    let next = current.checked_add(1).ok_or(Error::CounterExhausted)?;
+    env.storage().instance().set(&DataKey::SnapshotCounter, &next);
+    Ok(next)
+}
+
+pub fn set_snapshot(env: &Env, snapshot_id: u64, snapshot: &PlanSnapshot) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Snapshot(snapshot_id), snapshot);
+}
+
+pub fn get_snapshot(env: &Env, snapshot_id: u64) -> Option<PlanSnapshot> {
+    env.storage().persistent().get(&DataKey::Snapshot(snapshot_id))
+}
+
+pub fn allow_scheduling_after_completion(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::AllowSchedulingAfterCompletion)
+        .unwrap_or(false)
+}
+
+pub fn set_allow_scheduling_after_completion(env: &Env, allowed: bool) {
+    env.storage()
+        .instance()
+        .set(&DataKey::AllowSchedulingAfterCompletion, &allowed);
+}
+
+/// `None` (the default) means no grace window is configured, so once
+/// `allow_scheduling_after_completion` is on, scheduling is unrestricted.
+pub fn post_discharge_scheduling_window(env: &Env) -> Option<u64> {
+    env.storage()
+        .instance()
+        .get(&DataKey::PostDischargeSchedulingWindow)
+}
+
+pub fn set_post_dc_scheduling_window(env: &Env, seconds: u64) {
+    env.storage()
+        .instance()
+        .set(&DataKey::PostDischargeSchedulingWindow, &seconds);
+}
+
+pub fn consent_record(env: &Env, plan_id: u64) -> Option<ConsentRecord> {
+    env.storage().persistent().get(&DataKey::Consent(plan_id))
+}
+
+pub fn set_consent_record(env: &Env, plan_id: u64, record: &ConsentRecord) {
+    env.storage().persistent().set(&DataKey::Consent(plan_id), record);
+}
+
+pub fn snf_coordination(env: &Env, plan_id: u64) -> Option<SnfCoordination> {
+    env.storage().persistent().get(&DataKey::SnfCoordination(plan_id))
+}
+
+pub fn set_snf_coordination(env: &Env, plan_id: u64, coordination: &SnfCoordination) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::SnfCoordination(plan_id), coordination);
+}
+
+/// Permission bits a physician can grant a delegate via `delegate_plan_access`.
+/// Only `PERMISSION_READINESS` is checked by a `*_as_delegate` entry point
+/// today; the others are reserved for delegated order/education actions.
+pub const PERMISSION_READINESS: u32 = 0b001;
+#[allow(dead_code)]
+pub const PERMISSION_ORDERS: u32 = 0b010;
+#[allow(dead_code)]
+pub const PERMISSION_EDUCATION: u32 = 0b100;
+
+pub fn delegate_permissions(env: &Env, plan_id: u64, delegate: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Delegation(plan_id, delegate.clone()))
+        .unwrap_or(0)
+}
+
+pub fn set_delegate_permissions(env: &Env, plan_id: u64, delegate: &Address, permissions: u32) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Delegation(plan_id, delegate.clone()), &permissions);
+}
+
+pub fn clear_delegate_permissions(env: &Env, plan_id: u64, delegate: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Delegation(plan_id, delegate.clone()));
+}
+
+/// True if `caller` holds a delegation granted by `delegate_plan_access`
+/// that includes `permission`. `attending_provider_id` is a bare `u64`
+/// with no link to an `Address`, so there is no attending-provider bypass
+/// here; only an explicit delegation grants access.
+pub fn can_act_on_plan(env: &Env, plan_id: u64, caller: &Address, permission: u32) -> bool {
+    delegate_permissions(env, plan_id, caller) & permission == permission
+}
+
+pub fn drg_index(env: &Env, drg_code: u32) -> Vec<u64> {
+    env.storage()
+        .instance()
+        .get(&DataKey::DrgIndex(drg_code))
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn add_to_drg_index(env: &Env, drg_code: u32, plan_id: u64) {
+    let mut ids = drg_index(env, drg_code);
+    ids.push_back(plan_id);
+    env.storage().instance().set(&DataKey::DrgIndex(drg_code), &ids);
+}
+
+pub fn diagnosis_index(env: &Env, icd_code: u32) -> Vec<u64> {
+    env.storage()
+        .instance()
+        .get(&DataKey::DiagnosisIndex(icd_code))
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn add_to_diagnosis_index(env: &Env, icd_code: u32, plan_id: u64) {
+    let mut ids = diagnosis_index(env, icd_code);
+    ids.push_back(plan_id);
+    env.storage()
+        .instance()
+        .set(&DataKey::DiagnosisIndex(icd_code), &ids);
+}
+
+pub fn pathway_index(env: &Env, pathway_id: u32) -> Vec<u64> {
+    env.storage()
+        .instance()
+        .get(&DataKey::PathwayIndex(pathway_id))
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn add_to_pathway_index(env: &Env, pathway_id: u32, plan_id: u64) {
+    let mut ids = pathway_index(env, pathway_id);
+    ids.push_back(plan_id);
+    env.storage()
+        .instance()
+        .set(&DataKey::PathwayIndex(pathway_id), &ids);
+}
+
+pub fn readmission_count(env: &Env, patient_id: u64) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ReadmissionCount(patient_id))
+        .unwrap_or(0)
+}
+
+pub fn increment_readmission_count(env: &Env, patient_id: u64) -> u32 {
+    let next = readmission_count(env, patient_id) + 1;
+    env.storage()
+        .persistent()
+        .set(&DataKey::ReadmissionCount(patient_id), &next);
+    next
+}
+
+pub fn completion_details(env: &Env, plan_id: u64) -> Option<CompletionDetails> {
+    env.storage().persistent().get(&DataKey::Completion(plan_id))
+}
+
+pub fn set_completion_details(env: &Env, plan_id: u64, details: &CompletionDetails) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Completion(plan_id), details);
+}
+
+pub fn destination_index(env: &Env, destination: u32) -> Vec<u64> {
+    env.storage()
+        .instance()
+        .get(&DataKey::DestinationIndex(destination))
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn add_to_destination_index(env: &Env, destination: u32, plan_id: u64) {
+    let mut ids = destination_index(env, destination);
+    ids.push_back(plan_id);
+    env.storage()
+        .instance()
+        .set(&DataKey::DestinationIndex(destination), &ids);
+}
+
+pub fn remove_from_destination_index(env: &Env, destination: u32, plan_id: u64) {
+    let ids = destination_index(env, destination);
+    let mut filtered = Vec::new(env);
+    for id in ids.iter() {
+        if id != plan_id {
+            filtered.push_back(id);
+        }
+    }
+    env.storage()
+        .instance()
+        .set(&DataKey::DestinationIndex(destination), &filtered);
+}
+
+pub fn provider_index(env: &Env, provider_id: u64) -> Vec<u64> {
+    env.storage()
+        .instance()
+        .get(&DataKey::ProviderIndex(provider_id))
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn add_to_provider_index(env: &Env, provider_id: u64, plan_id: u64) {
+    let mut ids = provider_index(env, provider_id);
+    ids.push_back(plan_id);
+    env.storage()
+        .instance()
+        .set(&DataKey::ProviderIndex(provider_id), &ids);
+}
+
+pub fn remove_from_provider_index(env: &Env, provider_id: u64, plan_id: u64) {
+    let ids = provider_index(env, provider_id);
+    let mut filtered = Vec::new(env);
+    for id in ids.iter() {
+        if id != plan_id {
+            filtered.push_back(id);
+        }
+    }
+    env.storage()
+        .instance()
+        .set(&DataKey::ProviderIndex(provider_id), &filtered);
+}
+
+pub fn domain_progress(env: &Env, plan_id: u64) -> DomainProgress {
+    env.storage()
+        .persistent()
+        .get(&DataKey::DomainProgress(plan_id))
+        .unwrap_or_else(|| DomainProgress::empty(env))
+}
+
+pub fn set_domain_progress(env: &Env, plan_id: u64, progress: &DomainProgress) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::DomainProgress(plan_id), progress);
+}
+
+pub fn clear_domain_progress(env: &Env, plan_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::DomainProgress(plan_id));
+}
+
+pub fn caregivers(env: &Env, plan_id: u64) -> Caregivers {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Caregivers(plan_id))
+        .unwrap_or(Caregivers::new(env))
+}
+
+pub fn push_caregiver(env: &Env, plan_id: u64, caregiver: Caregiver) {
+    let mut list = caregivers(env, plan_id);
+    list.push_back(caregiver);
+    env.storage()
+        .persistent()
+        .set(&DataKey::Caregivers(plan_id), &list);
+}
+
+pub fn risk_history(env: &Env, plan_id: u64) -> Vec<RiskRecord> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RiskHistory(plan_id))
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn push_risk_record(env: &Env, plan_id: u64, record: RiskRecord) {
+    let mut history = risk_history(env, plan_id);
+    history.push_back(record);
+    env.storage()
+        .persistent()
+        .set(&DataKey::RiskHistory(plan_id), &history);
+}
+
+pub fn escalated_plan_ids(env: &Env) -> Vec<u64> {
+    env.storage()
+        .instance()
+        .get(&DataKey::EscalatedIndex)
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn add_to_escalated_index(env: &Env, plan_id: u64) {
+    let mut ids = escalated_plan_ids(env);
+    if !ids.iter().any(|id| id == plan_id) {
+        ids.push_back(plan_id);
+        env.storage().instance().set(&DataKey::EscalatedIndex, &ids);
+    }
+}
+
+pub fn readiness_preset(env: &Env, preset_id: u32) -> Option<Vec<u32>> {
+    env.storage()
+        .instance()
+        .get(&DataKey::ReadinessPreset(preset_id))
+}
+
+pub fn set_readiness_preset(env: &Env, preset_id: u32, weights: Vec<u32>) {
+    env.storage()
+        .instance()
+        .set(&DataKey::ReadinessPreset(preset_id), &weights);
+}
+
+/// Per-factor weights used by `compute_readmission_risk`, defaulting to an
+/// equal split across `RISK_FACTOR_COUNT` factors until an admin tunes them
+/// via `set_risk_factor_weights`.
+pub fn risk_factor_weights(env: &Env) -> Vec<u32> {
+    env.storage()
+        .instance()
+        .get(&DataKey::RiskFactorWeights)
+        .unwrap_or_else(|| {
+            let mut defaults = Vec::new(env);
+            for _ in 0..RISK_FACTOR_COUNT {
+                defaults.push_back(25);
+            }
+            defaults
+        })
+}
+
+pub fn set_risk_factor_weights(env: &Env, weights: Vec<u32>) {
+    env.storage()
+        .instance()
+        .set(&DataKey::RiskFactorWeights, &weights);
+}
+
+pub fn get_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Admin)
+}
+
+pub fn set_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&DataKey::Admin, admin);
+}
+
+/// Confirms `admin` authorized this call and matches the configured admin.
+/// While no admin is stored, the first caller is recorded as the admin and
+/// treated as authoritative going forward; the contract has no separate
+/// initialization step.
+pub fn require_admin(env: &Env, admin: &Address) -> Result<(), Error> {
+    admin.require_auth();
+    match get_admin(env) {
+        Some(stored) if &stored == admin => Ok(()),
+        Some(_) => Err(Error::NotAuthorized),
+        None => {
+            set_admin(env, admin);
+            Ok(())
+        }
+    }
+}
+
+pub fn plan_counter(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::PlanCounter)
+        .unwrap_or(0)
+}
+
+pub fn get_and_increment_plan_counter(env: &Env) -> Result<u64, Error> {
+    let current = env
+        .storage()
+        .instance()
+        .get::<_, u64>(&DataKey::PlanCounter)
+        .unwrap_or(0);
+    let next = current.checked_add(1).ok_or(Error::CounterExhausted)?;
+    env.storage().instance().set(&DataKey::PlanCounter, &next);
+    Ok(next)
+}
+
+pub fn get_plan(env: &Env, plan_id: u64) -> Result<DischargePlan, Error> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Plan(plan_id))
+        .ok_or(Error::PlanNotFound)
+}
+
+pub fn set_plan(env: &Env, plan: &DischargePlan) {
+    let mut plan = plan.clone();
+    plan.last_updated = env.ledger().timestamp();
+    env.storage().persistent().set(&DataKey::Plan(plan.id), &plan);
+}
+
+pub fn remove_plan(env: &Env, plan_id: u64) {
+    env.storage().persistent().remove(&DataKey::Plan(plan_id));
+}
+
+pub fn readiness_history(env: &Env, plan_id: u64) -> Vec<ReadinessAssessment> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ReadinessHistory(plan_id))
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn push_readiness(env: &Env, plan_id: u64, assessment: ReadinessAssessment) {
+    let mut history = readiness_history(env, plan_id);
+    history.push_back(assessment);
+    env.storage()
+        .persistent()
+        .set(&DataKey::ReadinessHistory(plan_id), &history);
+}
+
+pub fn orders(env: &Env, plan_id: u64) -> OrderList {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Orders(plan_id))
+        .unwrap_or(OrderList::new(env))
+}
+
+pub fn push_order(env: &Env, plan_id: u64, order: DischargeOrder) {
+    let mut list = orders(env, plan_id);
+    list.push_back(order);
+    env.storage().persistent().set(&DataKey::Orders(plan_id), &list);
+}
+
+pub fn set_orders(env: &Env, plan_id: u64, list: &OrderList) {
+    env.storage().persistent().set(&DataKey::Orders(plan_id), list);
+}
+
+pub fn event_log(env: &Env, plan_id: u64) -> EventLog {
+    env.storage()
+        .persistent()
+        .get(&DataKey::EventLog(plan_id))
+        .unwrap_or(EventLog::new(env))
+}
+
+pub fn push_event_log(env: &Env, plan_id: u64, code: u32) {
+    let mut log = event_log(env, plan_id);
+    log.push_back(EventLogEntry {
+        code,
+        timestamp: env.ledger().timestamp(),
+    });
+    env.storage().persistent().set(&DataKey::EventLog(plan_id), &log);
+}
+
+pub fn education_records(env: &Env, plan_id: u64) -> EducationRecords {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Education(plan_id))
+        .unwrap_or(EducationRecords::new(env))
+}
+
+pub fn push_education_record(env: &Env, plan_id: u64, record: EducationRecord) {
+    let mut records = education_records(env, plan_id);
+    records.push_back(record);
+    env.storage()
+        .persistent()
+        .set(&DataKey::Education(plan_id), &records);
+}
+
+pub fn appointment_counter(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::AppointmentCounter)
+        .unwrap_or(0)
+}
+
+pub fn get_and_increment_appointment_counter(env: &Env) -> Result<u64, Error> {
+    let current = env
+        .storage()
+        .instance()
+        .get::<_, u64>(&DataKey::AppointmentCounter)
+        .unwrap_or(0);
+    let next = current.checked_add(1).ok_or(Error::CounterExhausted)?;
+    env.storage()
+        .instance()
+        .set(&DataKey::AppointmentCounter, &next);
+    Ok(next)
+}
+
+/// Test-only helper to seed a counter near its limit without replaying
+/// thousands of increments.
+#[cfg(test)]
+pub fn set_plan_counter(env: &Env, value: u64) {
+    env.storage().instance().set(&DataKey::PlanCounter, &value);
+}
+
+pub fn appointments(env: &Env, plan_id: u64) -> Appointments {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Appointments(plan_id))
+        .unwrap_or(Appointments::new(env))
+}
+
+pub fn set_appointments(env: &Env, plan_id: u64, list: &Appointments) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Appointments(plan_id), list);
+}
+
+pub fn max_appointments_per_plan(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::MaxAppointmentsPerPlan)
+        .unwrap_or(u32::MAX)
+}
+
+pub fn set_max_appointments_per_plan(env: &Env, max: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::MaxAppointmentsPerPlan, &max);
+}
+
+/// CMS transitional-care rules default to 14 days when unset.
+pub fn pcp_followup_window_days(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::PcpFollowupWindowDays)
+        .unwrap_or(14)
+}
+
+pub fn set_pcp_followup_window_days(env: &Env, days: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::PcpFollowupWindowDays, &days);
+}
+
+pub fn home_health_arrangement(env: &Env, plan_id: u64) -> Option<HomeHealthArrangement> {
+    env.storage().persistent().get(&DataKey::HomeHealth(plan_id))
+}
+
+pub fn set_home_health_arrangement(env: &Env, plan_id: u64, arrangement: &HomeHealthArrangement) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::HomeHealth(plan_id), arrangement);
+}
@@ -0,0 +1,2375 @@
+use soroban_sdk::{
+    contract, contractimpl, symbol_short,
+    testutils::{Address as _, Events as _, Ledger as _},
+    vec, Address, Bytes, BytesN, Env, TryFromVal, Vec,
+};
+
+use crate::{DischargePlanningContract, DischargePlanningContractClient};
+
+/// Starts the ledger at 1_000, the admission date most tests use, so
+/// clinical actions on those plans pass `require_admitted`.
+fn setup(env: &Env) -> (DischargePlanningContractClient, Address) {
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let contract_id = env.register_contract(None, DischargePlanningContract);
+    let client = DischargePlanningContractClient::new(env, &contract_id);
+    let caller = Address::generate(env);
+    (client, caller)
+}
+
+/// Minimal event-subscriber contract used to verify `subscribe_plan_events`
+/// actually cross-calls subscribers; it just counts how many times
+/// `notify` was invoked.
+#[contract]
+struct MockSubscriber;
+
+#[contractimpl]
+impl MockSubscriber {
+    pub fn notify(env: Env, _plan_id: u64, _event_code: u32) {
+        let count: u32 = env.storage().instance().get(&0u32).unwrap_or(0);
+        env.storage().instance().set(&0u32, &(count + 1));
+    }
+
+    pub fn notify_count(env: Env) -> u32 {
+        env.storage().instance().get(&0u32).unwrap_or(0)
+    }
+}
+
+#[test]
+fn verify_plan_integrity_returns_true_for_a_consistent_plan() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    client.assess_discharge_readiness(&caller, &plan_id, &vec![&env, 80, 80, 80, 80]);
+    client.create_discharge_orders(&caller, &plan_id, &0, &BytesN::from_array(&env, &[1; 32]));
+    client.complete_discharge(&caller, &plan_id, &2_500, &BytesN::from_array(&env, &[7; 32]));
+
+    assert!(client.verify_plan_integrity(&plan_id));
+}
+
+#[test]
+fn set_patient_language_is_surfaced_and_checked_by_education() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    client.set_patient_language(&caller, &plan_id, &2);
+
+    let plan = client.get_discharge_plan(&plan_id);
+    assert_eq!(plan.language_code, 2);
+
+    client.provide_discharge_education(&caller, &plan_id, &0, &true, &2, &false);
+    client.provide_discharge_education(&caller, &plan_id, &1, &true, &5, &false);
+
+    let records = client.get_education_records(&plan_id);
+    assert!(records.get(0).unwrap().language_matched);
+    assert!(!records.get(1).unwrap().language_matched);
+}
+
+#[test]
+fn schedule_followup_appointments_respects_the_configured_max() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    client.set_max_appointments_per_plan(&caller, &2);
+
+    let zero_hash = BytesN::from_array(&env, &[0; 32]);
+    let ids = client.schedule_followup_appointments(
+        &caller,
+        &plan_id,
+        &vec![
+            &env,
+            (10, 0, 3_000, zero_hash.clone()),
+            (11, 1, 3_100, zero_hash.clone()),
+        ],
+    );
+    assert_eq!(ids.len(), 2);
+
+    let result = client.try_schedule_followup_appointments(
+        &caller,
+        &plan_id,
+        &vec![&env, (12, 0, 3_200, zero_hash)],
+    );
+    assert_eq!(result, Err(Ok(crate::Error::TooManyAppointments)));
+}
+
+#[test]
+fn update_appointment_status_and_get_appointment_adherence() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    let zero_hash = BytesN::from_array(&env, &[0; 32]);
+    let ids = client.schedule_followup_appointments(
+        &caller,
+        &plan_id,
+        &vec![
+            &env,
+            (10, 0, 3_000, zero_hash.clone()),
+            (11, 1, 3_100, zero_hash.clone()),
+            (12, 1, 3_200, zero_hash),
+        ],
+    );
+
+    assert_eq!(client.get_appointment_adherence(&plan_id), (0, 3));
+
+    client.update_appointment_status(&caller, &plan_id, &ids.get(0).unwrap(), &1);
+    client.update_appointment_status(&caller, &plan_id, &ids.get(1).unwrap(), &2);
+
+    assert_eq!(client.get_appointment_adherence(&plan_id), (1, 3));
+    assert_eq!(
+        client
+            .get_followup_appointments(&plan_id)
+            .get(1)
+            .unwrap()
+            .status,
+        2
+    );
+
+    let invalid = client.try_update_appointment_status(&caller, &plan_id, &ids.get(2).unwrap(), &9);
+    assert_eq!(invalid, Err(Ok(crate::Error::InvalidInput)));
+}
+
+#[test]
+fn get_remaining_home_health_visits_floors_at_planned_minus_completed() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    client.arrange_home_health(&caller, &plan_id, &3, &4);
+    for _ in 0..5 {
+        client.record_home_health_visit(&caller, &plan_id);
+    }
+
+    assert_eq!(client.get_remaining_home_health_visits(&plan_id), 7);
+}
+
+#[test]
+fn assess_discharge_readiness_with_preset_uses_registered_weights() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    client.set_readiness_preset(&caller, &1, &vec![&env, 4, 1, 1, 1]);
+
+    let total = client.assess_dc_readiness_with_preset(
+        &caller,
+        &plan_id,
+        &vec![&env, 100, 0, 0, 0],
+        &1,
+        &false,
+    );
+    assert_eq!(total, 57);
+
+    let result = client.try_assess_dc_readiness_with_preset(
+        &caller,
+        &plan_id,
+        &vec![&env, 100, 0, 0, 0],
+        &99,
+        &false,
+    );
+    assert_eq!(result, Err(Ok(crate::Error::UnknownPreset)));
+}
+
+#[test]
+fn export_then_import_plan_blob_preserves_all_fields() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &42, &1_000, &2_000, &1);
+    client.assess_discharge_readiness(&caller, &plan_id, &vec![&env, 80, 80, 80, 80]);
+    client.create_discharge_orders(&caller, &plan_id, &0, &BytesN::from_array(&env, &[9; 32]));
+
+    let blob = client.export_plan_blob(&caller, &plan_id);
+    let new_id = client.import_plan_blob(&caller, &blob);
+
+    let original = client.get_discharge_plan(&plan_id);
+    let imported = client.get_discharge_plan(&new_id);
+    assert_eq!(imported.patient_id, original.patient_id);
+    assert_eq!(imported.destination, original.destination);
+    assert_eq!(client.get_latest_readiness(&new_id), client.get_latest_readiness(&plan_id));
+    assert_eq!(client.get_discharge_orders(&new_id).len(), client.get_discharge_orders(&plan_id).len());
+}
+
+#[test]
+fn readiness_is_blocked_until_ledger_time_passes_a_future_admission() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let admission_date = env.ledger().timestamp() + 10_000;
+    let plan_id =
+        client.initiate_discharge_planning(&caller, &1, &1, &admission_date, &(admission_date + 1_000), &0);
+
+    let blocked = client.try_assess_discharge_readiness(&caller, &plan_id, &vec![&env, 80, 80, 80, 80]);
+    assert_eq!(blocked, Err(Ok(crate::Error::NotYetAdmitted)));
+
+    env.ledger().with_mut(|li| li.timestamp = admission_date + 1);
+    let total = client.assess_discharge_readiness(&caller, &plan_id, &vec![&env, 80, 80, 80, 80]);
+    assert_eq!(total, 80);
+}
+
+#[test]
+fn emergency_read_plan_returns_the_plan_and_emits_an_audit_event() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    let events_before = env.events().all().len();
+
+    let plan = client.emergency_read_plan(&caller, &plan_id, &7);
+    assert_eq!(plan.id, plan_id);
+    assert_eq!(env.events().all().len(), events_before + 1);
+}
+
+#[test]
+fn is_order_set_complete_requires_every_required_type_present() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    client.create_discharge_orders(&caller, &plan_id, &0, &BytesN::from_array(&env, &[1; 32]));
+
+    let required = vec![&env, 0u32, 1u32];
+    assert!(!client.is_order_set_complete(&plan_id, &required));
+
+    client.create_discharge_orders(&caller, &plan_id, &1, &BytesN::from_array(&env, &[2; 32]));
+    assert!(client.is_order_set_complete(&plan_id, &required));
+}
+
+#[test]
+fn get_supplier_pending_deliveries_scans_orders_across_plans() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_a = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    let plan_b = client.initiate_discharge_planning(&caller, &2, &1, &1_000, &2_000, &0);
+
+    client.create_discharge_orders(&caller, &plan_a, &9, &BytesN::from_array(&env, &[1; 32]));
+    let order_a = client.get_discharge_orders(&plan_a).get(0).unwrap().id;
+    client.set_order_supplier(&caller, &plan_a, &order_a, &42);
+    client.modify_dme_order(&caller, &plan_a, &order_a, &9, &5_000);
+
+    client.create_discharge_orders(&caller, &plan_b, &9, &BytesN::from_array(&env, &[2; 32]));
+    let order_b = client.get_discharge_orders(&plan_b).get(0).unwrap().id;
+    client.set_order_supplier(&caller, &plan_b, &order_b, &42);
+    client.modify_dme_order(&caller, &plan_b, &order_b, &9, &6_000);
+
+    client.create_discharge_orders(&caller, &plan_b, &9, &BytesN::from_array(&env, &[3; 32]));
+    let order_c = client.get_discharge_orders(&plan_b).get(1).unwrap().id;
+    client.set_order_supplier(&caller, &plan_b, &order_c, &7);
+
+    let pending =
+        client.get_supplier_pending_deliveries(&42, &vec![&env, plan_a, plan_b]);
+    assert_eq!(pending.len(), 2);
+    assert_eq!(pending.get(0).unwrap(), (plan_a, 9, 5_000));
+    assert_eq!(pending.get(1).unwrap(), (plan_b, 9, 6_000));
+}
+
+#[test]
+fn get_average_order_turnaround_averages_across_fulfilled_orders() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    client.create_discharge_orders(&caller, &plan_id, &0, &BytesN::from_array(&env, &[1; 32]));
+    client.create_discharge_orders(&caller, &plan_id, &1, &BytesN::from_array(&env, &[2; 32]));
+    let orders = client.get_discharge_orders(&plan_id);
+    let first_id = orders.get(0).unwrap().id;
+    let second_id = orders.get(1).unwrap().id;
+
+    assert_eq!(client.get_average_order_turnaround(&plan_id), 0);
+
+    env.ledger().with_mut(|li| li.timestamp = 2_100);
+    client.mark_order_fulfilled(&caller, &plan_id, &first_id);
+
+    env.ledger().with_mut(|li| li.timestamp = 2_300);
+    client.mark_order_fulfilled(&caller, &plan_id, &second_id);
+
+    assert_eq!(client.get_average_order_turnaround(&plan_id), 1_200);
+
+    let already_fulfilled =
+        client.try_mark_order_fulfilled(&caller, &plan_id, &first_id);
+    assert_eq!(already_fulfilled, Err(Ok(crate::Error::AlreadyDelivered)));
+}
+
+#[test]
+fn set_order_condition_marks_an_order_conditional_and_stores_its_hash() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    client.create_discharge_orders(&caller, &plan_id, &10, &BytesN::from_array(&env, &[1; 32]));
+    let order_id = client.get_discharge_orders(&plan_id).get(0).unwrap().id;
+
+    assert_eq!(
+        client.get_order_condition(&plan_id, &order_id),
+        (false, BytesN::from_array(&env, &[0; 32]))
+    );
+
+    let condition_hash = BytesN::from_array(&env, &[7; 32]);
+    client.set_order_condition(&caller, &plan_id, &order_id, &condition_hash);
+
+    assert_eq!(
+        client.get_order_condition(&plan_id, &order_id),
+        (true, condition_hash)
+    );
+}
+
+#[test]
+fn amend_discharge_order_preserves_the_original_hash_in_the_log() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    let original_hash = BytesN::from_array(&env, &[1; 32]);
+    client.create_discharge_orders(&caller, &plan_id, &10, &original_hash);
+    let order_id = client.get_discharge_orders(&plan_id).get(0).unwrap().id;
+
+    let amended_hash = BytesN::from_array(&env, &[2; 32]);
+    client.amend_discharge_order(&caller, &plan_id, &order_id, &amended_hash);
+
+    assert_eq!(
+        client.get_discharge_orders(&plan_id).get(0).unwrap().details_hash,
+        amended_hash
+    );
+
+    let log = client.get_order_amendments(&plan_id);
+    assert_eq!(log.len(), 1);
+    let entry = log.get(0).unwrap();
+    assert_eq!(entry.order_id, order_id);
+    assert_eq!(entry.previous_hash, original_hash);
+    assert_eq!(entry.new_hash, amended_hash);
+}
+
+#[test]
+fn high_readmission_risk_escalates_the_plan() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let high_risk_plan = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    let low_risk_plan = client.initiate_discharge_planning(&caller, &2, &1, &1_000, &2_000, &0);
+
+    client.track_readmission_risk(&caller, &high_risk_plan, &85, &0);
+    client.track_readmission_risk(&caller, &low_risk_plan, &20, &0);
+
+    assert!(client.get_discharge_plan(&high_risk_plan).escalated);
+    assert!(!client.get_discharge_plan(&low_risk_plan).escalated);
+
+    let worklist = client.get_escalated_plan_ids(&0, &10);
+    assert!(worklist.iter().any(|id| id == high_risk_plan));
+    assert!(!worklist.iter().any(|id| id == low_risk_plan));
+}
+
+#[test]
+fn get_readiness_breakdown_matches_the_stored_sub_scores() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    client.assess_discharge_readiness(&caller, &plan_id, &vec![&env, 90, 70, 60, 85]);
+
+    assert_eq!(client.get_readiness_breakdown(&plan_id), vec![&env, 90, 70, 60, 85]);
+}
+
+#[test]
+fn register_caregiver_stores_and_returns_two_caregivers() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    client.register_caregiver(&caller, &plan_id, &BytesN::from_array(&env, &[1; 32]), &0);
+    client.register_caregiver(&caller, &plan_id, &BytesN::from_array(&env, &[2; 32]), &1);
+
+    let caregivers = client.get_caregivers(&plan_id);
+    assert_eq!(caregivers.len(), 2);
+    assert_eq!(caregivers.get(1).unwrap().relationship, 1);
+}
+
+#[test]
+fn suggest_support_score_rewards_close_relationships() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let close_plan = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    client.register_caregiver(&caller, &close_plan, &BytesN::from_array(&env, &[1; 32]), &0);
+    client.register_caregiver(&caller, &close_plan, &BytesN::from_array(&env, &[2; 32]), &1);
+
+    let distant_plan = client.initiate_discharge_planning(&caller, &2, &1, &1_000, &2_000, &0);
+    client.register_caregiver(&caller, &distant_plan, &BytesN::from_array(&env, &[3; 32]), &3);
+
+    assert_eq!(client.suggest_support_score(&close_plan), 70);
+    assert_eq!(client.suggest_support_score(&distant_plan), 10);
+    assert!(client.suggest_support_score(&close_plan) > client.suggest_support_score(&distant_plan));
+}
+
+#[test]
+fn finalize_open_orders_sets_the_default_status_on_open_orders() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    client.create_discharge_orders(&caller, &plan_id, &0, &BytesN::from_array(&env, &[1; 32]));
+    client.create_discharge_orders(&caller, &plan_id, &1, &BytesN::from_array(&env, &[2; 32]));
+
+    client.finalize_open_orders(&caller, &plan_id, &2);
+
+    let orders = client.get_discharge_orders(&plan_id);
+    assert_eq!(orders.get(0).unwrap().status, Some(2));
+    assert_eq!(orders.get(1).unwrap().status, Some(2));
+}
+
+#[test]
+fn assess_readiness_domain_computes_total_once_all_domains_assessed() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+    let pt = Address::generate(&env);
+    let pharmacist = Address::generate(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    client.assess_readiness_domain(&pt, &plan_id, &0, &80);
+    client.assess_readiness_domain(&pharmacist, &plan_id, &1, &90);
+    client.assess_readiness_domain(&caller, &plan_id, &2, &70);
+    client.assess_readiness_domain(&caller, &plan_id, &3, &60);
+
+    assert_eq!(client.get_readiness_breakdown(&plan_id), vec![&env, 80, 90, 70, 60]);
+}
+
+#[test]
+fn get_plans_by_destination_returns_only_matching_plans() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let home_plan = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    let snf_plan = client.initiate_discharge_planning(&caller, &2, &1, &1_000, &2_000, &1);
+    client.initiate_discharge_planning(&caller, &3, &1, &1_000, &2_000, &0);
+
+    let snf_plans = client.get_plans_by_destination(&1, &0, &10);
+    assert_eq!(snf_plans.len(), 1);
+    assert_eq!(snf_plans.get(0).unwrap(), snf_plan);
+
+    client.change_discharge_destination(&caller, &home_plan, &1);
+    let snf_plans_after = client.get_plans_by_destination(&1, &0, &10);
+    assert_eq!(snf_plans_after.len(), 2);
+}
+
+#[test]
+fn get_plans_by_provider_partitions_plans_across_providers() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_a = client.initiate_discharge_planning(&caller, &1, &10, &1_000, &2_000, &0);
+    let plan_b = client.initiate_discharge_planning(&caller, &2, &20, &1_000, &2_000, &0);
+    client.initiate_discharge_planning(&caller, &3, &10, &1_000, &2_000, &0);
+
+    let provider_10_plans = client.get_plans_by_provider(&10, &0, &10);
+    assert_eq!(provider_10_plans.len(), 2);
+
+    let provider_20_plans = client.get_plans_by_provider(&20, &0, &10);
+    assert_eq!(provider_20_plans.len(), 1);
+    assert_eq!(provider_20_plans.get(0).unwrap(), plan_b);
+
+    client.reassign_attending_provider(&caller, &plan_a, &20);
+
+    assert_eq!(client.get_plans_by_provider(&10, &0, &10).len(), 1);
+    assert_eq!(client.get_plans_by_provider(&20, &0, &10).len(), 2);
+    assert_eq!(client.get_discharge_plan(&plan_a).attending_provider_id, 20);
+}
+
+#[test]
+fn purge_stale_drafts_only_archives_the_untouched_aged_out_plan() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let stale_draft = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+
+    env.ledger().with_mut(|li| li.timestamp = 10_000);
+    let active_plan = client.initiate_discharge_planning(&caller, &2, &1, &1_000, &2_000, &0);
+    client.assess_discharge_readiness(&caller, &active_plan, &vec![&env, 80, 80, 80, 80]);
+
+    env.ledger().with_mut(|li| li.timestamp = 20_000);
+    let archived =
+        client.purge_stale_drafts(&caller, &5_000, &vec![&env, stale_draft, active_plan]);
+
+    assert_eq!(archived, 1);
+    assert_eq!(
+        client.try_get_discharge_plan(&stale_draft),
+        Err(Ok(crate::Error::PlanNotFound))
+    );
+    assert!(client.get_discharge_plan(&active_plan).id == active_plan);
+}
+
+#[test]
+fn complete_discharge_rejects_a_zero_summary_hash() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    let result = client.try_complete_discharge(
+        &caller,
+        &plan_id,
+        &2_500,
+        &BytesN::from_array(&env, &[0; 32]),
+    );
+    assert_eq!(result, Err(Ok(crate::Error::MissingSummary)));
+}
+
+#[test]
+fn create_discharge_orders_is_blocked_until_a_future_admission_passes() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let admission_date = env.ledger().timestamp() + 10_000;
+    let plan_id =
+        client.initiate_discharge_planning(&caller, &1, &1, &admission_date, &(admission_date + 1_000), &0);
+
+    let blocked = client.try_create_discharge_orders(
+        &caller,
+        &plan_id,
+        &0,
+        &BytesN::from_array(&env, &[1; 32]),
+    );
+    assert_eq!(blocked, Err(Ok(crate::Error::NotYetAdmitted)));
+
+    env.ledger().with_mut(|li| li.timestamp = admission_date + 1);
+    client.create_discharge_orders(&caller, &plan_id, &0, &BytesN::from_array(&env, &[1; 32]));
+}
+
+#[test]
+fn complete_discharge_is_blocked_for_a_future_admission() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let admission_date = env.ledger().timestamp() + 10_000;
+    let plan_id =
+        client.initiate_discharge_planning(&caller, &1, &1, &admission_date, &(admission_date + 1_000), &0);
+
+    let blocked = client.try_complete_discharge(
+        &caller,
+        &plan_id,
+        &(admission_date + 500),
+        &BytesN::from_array(&env, &[1; 32]),
+    );
+    assert_eq!(blocked, Err(Ok(crate::Error::NotYetAdmitted)));
+
+    env.ledger().with_mut(|li| li.timestamp = admission_date + 1);
+    let stale_discharge_date = client.try_complete_discharge(
+        &caller,
+        &plan_id,
+        &(admission_date - 1),
+        &BytesN::from_array(&env, &[1; 32]),
+    );
+    assert_eq!(stale_discharge_date, Err(Ok(crate::Error::NotYetAdmitted)));
+
+    client.complete_discharge(&caller, &plan_id, &(admission_date + 1), &BytesN::from_array(&env, &[1; 32]));
+}
+
+#[test]
+fn get_prior_meds_for_readmission_requires_a_recorded_readmission() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    client.create_discharge_orders(&caller, &plan_id, &crate::ORDER_TYPE_MEDICATION, &BytesN::from_array(&env, &[1; 32]));
+    client.create_discharge_orders(&caller, &plan_id, &0, &BytesN::from_array(&env, &[2; 32]));
+
+    let not_yet = client.try_get_prior_meds_for_readmission(&plan_id);
+    assert_eq!(not_yet, Err(Ok(crate::Error::NotReadmitted)));
+
+    client.record_readmission(&caller, &plan_id);
+    let medications = client.get_prior_meds_for_readmission(&plan_id);
+    assert_eq!(medications.len(), 1);
+    assert_eq!(medications.get(0).unwrap().order_type, crate::ORDER_TYPE_MEDICATION);
+}
+
+#[test]
+fn create_dc_orders_with_scheme_round_trips_the_scheme_tag() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    client.create_discharge_orders(&caller, &plan_id, &0, &BytesN::from_array(&env, &[1; 32]));
+    client.create_dc_orders_with_scheme(
+        &caller,
+        &plan_id,
+        &1,
+        &BytesN::from_array(&env, &[2; 32]),
+        &7,
+    );
+
+    let orders = client.get_discharge_orders(&plan_id);
+    assert_eq!(orders.get(0).unwrap().scheme, crate::DEFAULT_ENCRYPTION_SCHEME);
+    assert_eq!(orders.get(1).unwrap().scheme, 7);
+}
+
+#[test]
+fn get_assessed_incomplete_plan_ids_excludes_untouched_and_completed_plans() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let untouched = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    let assessed_only = client.initiate_discharge_planning(&caller, &2, &1, &1_000, &2_000, &0);
+    let completed = client.initiate_discharge_planning(&caller, &3, &1, &1_000, &2_000, &0);
+
+    client.assess_discharge_readiness(&caller, &assessed_only, &vec![&env, 80, 80, 80, 80]);
+    client.assess_discharge_readiness(&caller, &completed, &vec![&env, 80, 80, 80, 80]);
+    client.complete_discharge(&caller, &completed, &2_500, &BytesN::from_array(&env, &[1; 32]));
+
+    let worklist = client.get_assessed_incomplete_plan_ids(&0, &10);
+    assert!(worklist.iter().any(|id| id == assessed_only));
+    assert!(!worklist.iter().any(|id| id == untouched));
+    assert!(!worklist.iter().any(|id| id == completed));
+}
+
+#[test]
+fn readiness_cosign_flow_requires_a_distinct_second_clinician() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+    let second_clinician = Address::generate(&env);
+
+    client.require_readiness_cosign(&caller, &true);
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+
+    client.assess_discharge_readiness(&caller, &plan_id, &vec![&env, 90, 90, 90, 90]);
+    assert!(!client.get_latest_readiness(&plan_id).is_ready);
+
+    let rejected = client.try_cosign_readiness_assessment(&caller, &plan_id);
+    assert_eq!(rejected, Err(Ok(crate::Error::CosignRequired)));
+    assert!(!client.get_latest_readiness(&plan_id).is_ready);
+
+    let is_ready = client.cosign_readiness_assessment(&second_clinician, &plan_id);
+    assert!(is_ready);
+    assert!(client.get_latest_readiness(&plan_id).is_ready);
+}
+
+#[test]
+fn complete_discharge_batch_skips_bad_items_without_failing_the_batch() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let valid_plan = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    let already_completed = client.initiate_discharge_planning(&caller, &2, &1, &1_000, &2_000, &0);
+    client.complete_discharge(&caller, &already_completed, &2_500, &BytesN::from_array(&env, &[1; 32]));
+    let nonexistent_plan = 999u64;
+
+    let hash = BytesN::from_array(&env, &[2; 32]);
+    let results = client.complete_discharge_batch(
+        &caller,
+        &vec![
+            &env,
+            (valid_plan, 2_500, hash.clone()),
+            (already_completed, 2_500, hash.clone()),
+            (nonexistent_plan, 2_500, hash.clone()),
+        ],
+    );
+
+    assert_eq!(
+        results,
+        vec![
+            &env,
+            (valid_plan, true),
+            (already_completed, false),
+            (nonexistent_plan, false),
+        ]
+    );
+}
+
+#[test]
+fn get_plans_missing_summary_flags_only_the_zero_hash_completion() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let with_summary = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    client.complete_discharge(&caller, &with_summary, &2_500, &BytesN::from_array(&env, &[1; 32]));
+
+    let legacy_plan = client.initiate_discharge_planning(&caller, &2, &1, &1_000, &2_000, &0);
+    env.as_contract(&client.address, || {
+        let mut plan = crate::storage::get_plan(&env, legacy_plan).unwrap();
+        plan.status = crate::STAGE_COMPLETED;
+        crate::storage::set_plan(&env, &plan);
+        crate::storage::set_completion_details(
+            &env,
+            legacy_plan,
+            &crate::CompletionDetails {
+                discharge_summary_hash: BytesN::from_array(&env, &[0; 32]),
+                completed_at: env.ledger().timestamp(),
+                hours_ready_to_discharge: None,
+                summary_cid: soroban_sdk::Bytes::new(&env),
+                threshold_at_completion: crate::DEFAULT_READINESS_THRESHOLD,
+            },
+        );
+    });
+
+    let missing = client.get_plans_missing_summary(&vec![&env, with_summary, legacy_plan]);
+    assert_eq!(missing, vec![&env, legacy_plan]);
+}
+
+#[test]
+fn same_score_is_ready_for_snf_but_not_for_home() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    client.set_readiness_threshold_by_dest(&caller, &crate::DESTINATION_HOME, &90);
+    client.set_readiness_threshold_by_dest(&caller, &crate::DESTINATION_SNF, &60);
+
+    let home_plan = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &crate::DESTINATION_HOME);
+    let snf_plan = client.initiate_discharge_planning(&caller, &2, &1, &1_000, &2_000, &crate::DESTINATION_SNF);
+
+    client.assess_discharge_readiness(&caller, &home_plan, &vec![&env, 70, 70, 70, 70]);
+    client.assess_discharge_readiness(&caller, &snf_plan, &vec![&env, 70, 70, 70, 70]);
+
+    assert!(!client.get_latest_readiness(&home_plan).is_ready);
+    assert!(client.get_latest_readiness(&snf_plan).is_ready);
+}
+
+#[test]
+fn stage_changed_events_fire_with_correct_from_to_across_a_workflow() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    client.assess_discharge_readiness(&caller, &plan_id, &vec![&env, 70, 70, 70, 70]);
+    client.create_discharge_orders(&caller, &plan_id, &10, &BytesN::from_array(&env, &[1; 32]));
+    client.complete_discharge(&caller, &plan_id, &2_500, &BytesN::from_array(&env, &[2; 32]));
+
+    let mut transitions: Vec<(u32, u32)> = Vec::new(&env);
+    for (_, topics, data) in env.events().all().iter() {
+        let topic = soroban_sdk::Symbol::try_from_val(&env, &topics.get(0).unwrap()).unwrap();
+        if topic == crate::events::TOPIC_STAGE_CHANGED {
+            let (id, from_stage, to_stage) =
+                <(u64, u32, u32)>::try_from_val(&env, &data).unwrap();
+            assert_eq!(id, plan_id);
+            transitions.push_back((from_stage, to_stage));
+        }
+    }
+
+    assert_eq!(
+        transitions,
+        vec![
+            &env,
+            (crate::STAGE_INITIATED, crate::STAGE_ASSESSED),
+            (crate::STAGE_ASSESSED, crate::STAGE_ORDERS_PLACED),
+            (crate::STAGE_ORDERS_PLACED, crate::STAGE_COMPLETED),
+        ]
+    );
+}
+
+#[test]
+fn initiate_discharge_planning_emits_the_init_topic_constant() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+
+    let (_, topics, _) = env.events().all().last().unwrap();
+    let topic = soroban_sdk::Symbol::try_from_val(&env, &topics.get(0).unwrap()).unwrap();
+    assert_eq!(topic, crate::events::TOPIC_INIT);
+}
+
+#[test]
+fn complete_discharge_is_blocked_without_consent_when_required() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    client.set_require_patient_consent(&caller, &plan_id, &true);
+
+    let blocked = client.try_complete_discharge(
+        &caller,
+        &plan_id,
+        &2_500,
+        &BytesN::from_array(&env, &[1; 32]),
+    );
+    assert_eq!(blocked, Err(Ok(crate::Error::ConsentMissing)));
+
+    client.record_patient_consent(&caller, &plan_id, &BytesN::from_array(&env, &[2; 32]), &true);
+    client.complete_discharge(&caller, &plan_id, &2_500, &BytesN::from_array(&env, &[1; 32]));
+}
+
+#[test]
+fn arrange_home_health_is_blocked_without_authorization_when_required() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    client.set_require_authorization(&caller, &plan_id, &true);
+
+    let blocked = client.try_arrange_home_health(&caller, &plan_id, &3, &4);
+    assert_eq!(blocked, Err(Ok(crate::Error::AuthorizationRequired)));
+
+    client.record_authorization(
+        &caller,
+        &plan_id,
+        &crate::SERVICE_TYPE_HOME_HEALTH,
+        &true,
+        &BytesN::from_array(&env, &[1; 32]),
+    );
+    client.arrange_home_health(&caller, &plan_id, &3, &4);
+}
+
+#[test]
+fn coordinate_with_snf_is_blocked_without_authorization_when_required() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    client.set_require_authorization(&caller, &plan_id, &true);
+
+    let blocked = client.try_coordinate_with_snf(&caller, &plan_id, &99, &1_500, &false, &false);
+    assert_eq!(blocked, Err(Ok(crate::Error::AuthorizationRequired)));
+
+    client.record_authorization(
+        &caller,
+        &plan_id,
+        &crate::SERVICE_TYPE_SNF,
+        &true,
+        &BytesN::from_array(&env, &[1; 32]),
+    );
+    client.coordinate_with_snf(&caller, &plan_id, &99, &1_500, &false, &false);
+}
+
+#[test]
+fn coordinate_with_snf_strict_mode_rejects_a_transfer_before_expected_discharge() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+
+    let rejected = client.try_coordinate_with_snf(&caller, &plan_id, &99, &1_500, &true, &false);
+    assert_eq!(rejected, Err(Ok(crate::Error::InvalidDate)));
+
+    client.coordinate_with_snf(&caller, &plan_id, &99, &1_500, &false, &false);
+    assert_eq!(client.get_snf_coordination(&plan_id).unwrap().transfer_date, 1_500);
+}
+
+#[test]
+fn get_days_since_admission_clamps_at_zero_before_admission() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &10_000, &0);
+
+    assert_eq!(client.get_days_since_admission(&plan_id, &(1_000 + 3 * 86_400)), 3);
+    assert_eq!(client.get_days_since_admission(&plan_id, &500), 0);
+}
+
+#[test]
+fn discharge_barriers_gate_completion_until_resolved() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    client.set_require_barriers_resolved(&caller, &plan_id, &true);
+    client.add_discharge_barrier(&caller, &plan_id, &0);
+    client.add_discharge_barrier(&caller, &plan_id, &1);
+
+    let blocked = client.try_complete_discharge(
+        &caller,
+        &plan_id,
+        &2_500,
+        &BytesN::from_array(&env, &[1; 32]),
+    );
+    assert_eq!(blocked, Err(Ok(crate::Error::OpenBarriersRemain)));
+
+    client.resolve_discharge_barrier(&caller, &plan_id, &0);
+    assert_eq!(client.get_open_barriers(&plan_id).len(), 1);
+
+    let still_blocked = client.try_complete_discharge(
+        &caller,
+        &plan_id,
+        &2_500,
+        &BytesN::from_array(&env, &[1; 32]),
+    );
+    assert_eq!(still_blocked, Err(Ok(crate::Error::OpenBarriersRemain)));
+
+    client.resolve_discharge_barrier(&caller, &plan_id, &1);
+    client.complete_discharge(&caller, &plan_id, &2_500, &BytesN::from_array(&env, &[1; 32]));
+}
+
+#[test]
+fn coordinate_with_snf_blocks_silent_overwrite_without_the_flag() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    client.coordinate_with_snf(&caller, &plan_id, &99, &2_100, &false, &false);
+
+    let blocked = client.try_coordinate_with_snf(&caller, &plan_id, &100, &2_200, &false, &false);
+    assert_eq!(blocked, Err(Ok(crate::Error::CoordinationExists)));
+
+    client.coordinate_with_snf(&caller, &plan_id, &100, &2_200, &false, &true);
+    assert_eq!(client.get_snf_coordination(&plan_id).unwrap().facility_id, 100);
+}
+
+#[test]
+fn get_risk_factor_prevalence_counts_bits_across_latest_records() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_a = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    let plan_b = client.initiate_discharge_planning(&caller, &2, &1, &1_000, &2_000, &0);
+    let plan_c = client.initiate_discharge_planning(&caller, &3, &1, &1_000, &2_000, &0);
+
+    client.track_readmission_risk(&caller, &plan_a, &50, &0b0011);
+    client.track_readmission_risk(&caller, &plan_b, &50, &0b0001);
+    client.track_readmission_risk(&caller, &plan_c, &50, &0b1000);
+
+    let counts =
+        client.get_risk_factor_prevalence(&vec![&env, plan_a, plan_b, plan_c]);
+    assert_eq!(counts, vec![&env, 2, 1, 0, 1]);
+}
+
+#[test]
+fn delegate_can_assess_readiness_only_with_the_matching_permission() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+    let nurse = Address::generate(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    client.register_physician(&caller, &caller);
+
+    let rejected =
+        client.try_assess_dc_readiness_as_delegate(&nurse, &plan_id, &vec![&env, 80, 80, 80, 80]);
+    assert_eq!(rejected, Err(Ok(crate::Error::NotAuthorized)));
+
+    client.delegate_plan_access(&caller, &plan_id, &nurse, &crate::storage::PERMISSION_READINESS);
+    let total = client.assess_dc_readiness_as_delegate(&nurse, &plan_id, &vec![&env, 80, 80, 80, 80]);
+    assert_eq!(total, 80);
+
+    client.revoke_plan_access(&caller, &plan_id, &nurse);
+    let rejected_after_revoke =
+        client.try_assess_dc_readiness_as_delegate(&nurse, &plan_id, &vec![&env, 80, 80, 80, 80]);
+    assert_eq!(rejected_after_revoke, Err(Ok(crate::Error::NotAuthorized)));
+}
+
+#[test]
+fn delegate_plan_access_rejects_a_caller_not_registered_as_a_physician() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+    let nurse = Address::generate(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+
+    let rejected = client.try_delegate_plan_access(
+        &caller,
+        &plan_id,
+        &nurse,
+        &crate::storage::PERMISSION_READINESS,
+    );
+    assert_eq!(rejected, Err(Ok(crate::Error::NotAuthorized)));
+}
+
+#[test]
+fn revoke_plan_access_rejects_a_caller_not_registered_as_a_physician() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+    let nurse = Address::generate(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    client.register_physician(&caller, &caller);
+    client.delegate_plan_access(&caller, &plan_id, &nurse, &crate::storage::PERMISSION_READINESS);
+
+    let unregistered_caller = Address::generate(&env);
+    let rejected = client.try_revoke_plan_access(&unregistered_caller, &plan_id, &nurse);
+    assert_eq!(rejected, Err(Ok(crate::Error::NotAuthorized)));
+}
+
+#[test]
+fn complete_discharge_computes_hours_ready_to_discharge() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    client.assess_discharge_readiness(&caller, &plan_id, &vec![&env, 90, 90, 90, 90]);
+
+    let ready_at = client.get_latest_readiness(&plan_id).assessed_at;
+    let actual_discharge_date = ready_at + 7_200;
+    client.complete_discharge(
+        &caller,
+        &plan_id,
+        &actual_discharge_date,
+        &BytesN::from_array(&env, &[1; 32]),
+    );
+
+    let details = client.get_completion_details(&plan_id);
+    assert_eq!(details.hours_ready_to_discharge, Some(2));
+}
+
+#[test]
+fn complete_discharge_is_blocked_without_a_followup_when_required() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    client.set_require_followup_before_dc(&caller, &plan_id, &true);
+
+    let blocked = client.try_complete_discharge(
+        &caller,
+        &plan_id,
+        &2_500,
+        &BytesN::from_array(&env, &[1; 32]),
+    );
+    assert_eq!(blocked, Err(Ok(crate::Error::NoFollowupScheduled)));
+
+    client.schedule_followup_appointments(
+        &caller,
+        &plan_id,
+        &vec![&env, (10, 0, 2_100, BytesN::from_array(&env, &[0; 32]))],
+    );
+    client.complete_discharge(&caller, &plan_id, &2_500, &BytesN::from_array(&env, &[1; 32]));
+}
+
+#[test]
+fn set_drg_code_is_reflected_on_the_plan_and_the_drg_index() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    client.set_drg_code(&caller, &plan_id, &470);
+
+    assert_eq!(client.get_discharge_plan(&plan_id).drg_code, Some(470));
+
+    let matching = client.get_plans_by_drg(&470, &0, &10);
+    assert_eq!(matching.len(), 1);
+    assert_eq!(matching.get(0).unwrap(), plan_id);
+}
+
+#[test]
+fn initiate_discharge_planning_fails_gracefully_when_the_counter_is_exhausted() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    env.as_contract(&client.address, || {
+        crate::storage::set_plan_counter(&env, u64::MAX);
+    });
+
+    let result = client.try_initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    assert_eq!(result, Err(Ok(crate::Error::CounterExhausted)));
+}
+
+#[test]
+fn get_incomplete_education_topics_returns_only_wound_care() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    client.provide_discharge_education(&caller, &plan_id, &0, &true, &0, &false);
+    client.provide_discharge_education(&caller, &plan_id, &1, &false, &0, &false);
+
+    let required = vec![&env, 0u32, 1u32];
+    let incomplete = client.get_incomplete_education_topics(&plan_id, &required);
+    assert_eq!(incomplete, vec![&env, 1u32]);
+}
+
+#[test]
+fn get_provided_education_topics_lists_incomplete_and_complete_topics() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    client.provide_discharge_education(&caller, &plan_id, &0, &false, &0, &false);
+    client.provide_discharge_education(&caller, &plan_id, &1, &true, &0, &false);
+
+    let provided = client.get_provided_education_topics(&plan_id);
+    assert_eq!(provided, vec![&env, 0u32, 1u32]);
+}
+
+#[test]
+fn assess_discharge_readiness_with_preset_rejects_out_of_range_sub_scores() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    client.set_readiness_preset(&caller, &1, &vec![&env, 1, 1, 1, 1]);
+
+    let result = client.try_assess_dc_readiness_with_preset(
+        &caller,
+        &plan_id,
+        &vec![&env, 200, 0, 0, 0],
+        &1,
+        &false,
+    );
+    assert_eq!(result, Err(Ok(crate::Error::InvalidScore)));
+}
+
+#[test]
+fn assess_discharge_readiness_with_preset_strict_mode_rejects_zero_weights() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    client.set_readiness_preset(&caller, &1, &vec![&env, 4, 1, 1, 0]);
+
+    let rejected = client.try_assess_dc_readiness_with_preset(
+        &caller, &plan_id, &vec![&env, 100, 0, 0, 0], &1, &true,
+    );
+    assert_eq!(rejected, Err(Ok(crate::Error::InvalidWeights)));
+
+    let accepted = client.assess_dc_readiness_with_preset(
+        &caller, &plan_id, &vec![&env, 100, 0, 0, 0], &1, &false,
+    );
+    assert_eq!(accepted, 66);
+}
+
+#[test]
+fn assess_discharge_readiness_strict_rejects_an_all_zero_submission() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+
+    let rejected =
+        client.try_assess_dc_readiness_strict(&caller, &plan_id, &vec![&env, 0, 0, 0, 0]);
+    assert_eq!(rejected, Err(Ok(crate::Error::InvalidScore)));
+
+    let total = client.assess_dc_readiness_strict(&caller, &plan_id, &vec![&env, 0, 0, 0, 40]);
+    assert_eq!(total, 10);
+
+    let non_strict_allows_all_zero =
+        client.assess_discharge_readiness(&caller, &plan_id, &vec![&env, 0, 0, 0, 0]);
+    assert_eq!(non_strict_allows_all_zero, 0);
+}
+
+#[test]
+fn schedule_recurring_followup_generates_four_weekly_appointments() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    let first_time = env.ledger().timestamp() + 1_000;
+    let week = 7 * 24 * 3600;
+
+    let ids = client.schedule_recurring_followup(
+        &caller,
+        &plan_id,
+        &10,
+        &0,
+        &first_time,
+        &week,
+        &4,
+    );
+    assert_eq!(ids.len(), 4);
+
+    let appointments = client.get_followup_appointments(&plan_id);
+    for i in 0..4u64 {
+        assert_eq!(appointments.get(i as u32).unwrap().time, first_time + week * i);
+    }
+}
+
+#[test]
+fn get_full_plan_export_populates_every_sub_collection() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    client.assess_discharge_readiness(&caller, &plan_id, &vec![&env, 80, 80, 80, 80]);
+    client.create_discharge_orders(&caller, &plan_id, &0, &BytesN::from_array(&env, &[1; 32]));
+    client.arrange_home_health(&caller, &plan_id, &3, &4);
+    client.schedule_followup_appointments(
+        &caller,
+        &plan_id,
+        &vec![&env, (10, 0, 3_000, BytesN::from_array(&env, &[0; 32]))],
+    );
+    client.provide_discharge_education(&caller, &plan_id, &0, &true, &0, &false);
+    client.track_readmission_risk(&caller, &plan_id, &40, &0);
+
+    let export = client.get_full_plan_export(&plan_id);
+    assert_eq!(export.plan.id, plan_id);
+    assert!(!export.readiness_history.is_empty());
+    assert!(!export.orders.is_empty());
+    assert!(export.home_health.is_some());
+    assert!(!export.appointments.is_empty());
+    assert!(!export.education_records.is_empty());
+    assert!(!export.risk_history.is_empty());
+}
+
+#[test]
+fn frequently_readmitted_patient_scores_higher_than_a_first_timer() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let frequent_flyer_plan = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    let first_timer_plan = client.initiate_discharge_planning(&caller, &2, &1, &1_000, &2_000, &0);
+
+    client.record_readmission(&caller, &frequent_flyer_plan);
+    client.record_readmission(&caller, &frequent_flyer_plan);
+
+    let frequent_flyer_estimate = client.estimate_readmission_probability(&1, &0b0011);
+    let first_timer_estimate = client.estimate_readmission_probability(&2, &0b0011);
+
+    assert!(frequent_flyer_estimate > first_timer_estimate);
+    let _ = first_timer_plan;
+}
+
+#[test]
+fn get_readmission_rate_skips_incomplete_plans_from_the_denominator() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let mut ids = vec![&env];
+    for patient_id in 1..=4u64 {
+        let plan_id =
+            client.initiate_discharge_planning(&caller, &patient_id, &1, &1_000, &2_000, &0);
+        client.complete_discharge(&caller, &plan_id, &2_500, &BytesN::from_array(&env, &[1; 32]));
+        ids.push_back(plan_id);
+    }
+    client.record_readmission(&caller, &ids.get(0).unwrap());
+
+    let still_open = client.initiate_discharge_planning(&caller, &5, &1, &1_000, &2_000, &0);
+    ids.push_back(still_open);
+
+    assert_eq!(client.get_readmission_rate(&ids), 25);
+}
+
+#[test]
+fn reassessing_readiness_appends_rather_than_overwrites_the_original_entry() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+
+    client.assess_discharge_readiness(&caller, &plan_id, &vec![&env, 40, 40, 40, 40]);
+    let original = client.get_latest_readiness(&plan_id);
+    assert_eq!(client.get_readiness_amendment_count(&plan_id), 1);
+
+    client.assess_discharge_readiness(&caller, &plan_id, &vec![&env, 90, 90, 90, 90]);
+    client.assess_discharge_readiness(&caller, &plan_id, &vec![&env, 95, 95, 95, 95]);
+    assert_eq!(client.get_readiness_amendment_count(&plan_id), 3);
+
+    let export = client.get_full_plan_export(&plan_id);
+    let first_entry = export.readiness_history.get(0).unwrap();
+    assert_eq!(first_entry, original);
+    assert_eq!(first_entry.total, 40);
+}
+
+#[test]
+fn set_care_pathway_is_reflected_on_the_plan_and_the_pathway_index() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let hip_plan = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    let other_plan = client.initiate_discharge_planning(&caller, &2, &1, &1_000, &2_000, &0);
+
+    client.set_care_pathway(&caller, &hip_plan, &7);
+
+    let matches = client.get_plans_by_pathway(&7, &0, &10);
+    assert_eq!(matches, vec![&env, hip_plan]);
+    let _ = other_plan;
+}
+
+#[test]
+fn get_readiness_gaps_identifies_sub_scores_below_the_floor() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    client.assess_discharge_readiness(&caller, &plan_id, &vec![&env, 90, 40, 90, 60]);
+
+    let gaps = client.get_readiness_gaps(&plan_id, &70);
+    assert_eq!(gaps, vec![&env, 1, 3]);
+}
+
+#[test]
+fn scheduled_appointment_retains_its_care_summary_hash() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    let summary_hash = BytesN::from_array(&env, &[9; 32]);
+
+    client.schedule_followup_appointments(
+        &caller,
+        &plan_id,
+        &vec![&env, (10, 0, 3_000, summary_hash.clone())],
+    );
+
+    let appointments = client.get_followup_appointments(&plan_id);
+    assert_eq!(appointments.get(0).unwrap().care_summary_hash, summary_hash);
+}
+
+#[test]
+fn get_effective_threshold_follows_plan_destination_global_then_default_precedence() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    assert_eq!(client.get_effective_threshold(&plan_id), 75);
+
+    client.set_global_readiness_threshold(&caller, &60, &false);
+    assert_eq!(client.get_effective_threshold(&plan_id), 60);
+
+    client.set_readiness_threshold_by_dest(&caller, &0, &65);
+    assert_eq!(client.get_effective_threshold(&plan_id), 65);
+
+    client.set_readiness_threshold_for_plan(&caller, &plan_id, &90);
+    assert_eq!(client.get_effective_threshold(&plan_id), 90);
+}
+
+#[test]
+fn lowering_the_global_threshold_with_recompute_flips_open_plans_ready() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    client.assess_discharge_readiness(&caller, &plan_id, &vec![&env, 60, 60, 60, 60]);
+    assert!(!client.get_latest_readiness(&plan_id).is_ready);
+
+    client.set_global_readiness_threshold(&caller, &50, &true);
+
+    assert!(client.get_latest_readiness(&plan_id).is_ready);
+}
+
+#[test]
+fn modify_dme_order_updates_the_order_until_it_is_delivered() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    let order_id = client.create_discharge_orders(
+        &caller,
+        &plan_id,
+        &crate::ORDER_TYPE_DME,
+        &BytesN::from_array(&env, &[1; 32]),
+    );
+
+    client.modify_dme_order(&caller, &plan_id, &order_id, &crate::ORDER_TYPE_DME, &5_000);
+    let order = client
+        .get_discharge_orders(&plan_id)
+        .iter()
+        .find(|o| o.id == order_id)
+        .unwrap();
+    assert_eq!(order.scheduled_for, 5_000);
+
+    client.finalize_open_orders(&caller, &plan_id, &1);
+    let result = client.try_modify_dme_order(&caller, &plan_id, &order_id, &crate::ORDER_TYPE_DME, &9_000);
+    assert_eq!(result, Err(Ok(crate::Error::AlreadyDelivered)));
+}
+
+#[test]
+fn record_vitals_check_nudges_medical_stability_sub_score() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    client.assess_discharge_readiness(&caller, &plan_id, &vec![&env, 50, 80, 80, 80]);
+
+    client.record_vitals_check(&caller, &plan_id, &false, &1_500);
+    assert_eq!(client.get_vitals_checks(&plan_id).len(), 1);
+
+    let latest = client.get_latest_readiness(&plan_id);
+    assert_eq!(latest.sub_scores.get(0).unwrap(), 35);
+
+    client.record_vitals_check(&caller, &plan_id, &true, &1_600);
+    let latest = client.get_latest_readiness(&plan_id);
+    assert_eq!(latest.sub_scores.get(0).unwrap(), 50);
+}
+
+#[test]
+fn scheduling_after_completion_is_blocked_unless_admin_allows_it() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    client.complete_discharge(&caller, &plan_id, &2_500, &BytesN::from_array(&env, &[1; 32]));
+
+    let zero_hash = BytesN::from_array(&env, &[0; 32]);
+    let blocked = client.try_schedule_followup_appointments(
+        &caller,
+        &plan_id,
+        &vec![&env, (10, 0, 3_000, zero_hash.clone())],
+    );
+    assert_eq!(blocked, Err(Ok(crate::Error::AlreadyCompleted)));
+
+    client.set_allow_scheduling_post_dc(&caller, &true);
+    let ids = client.schedule_followup_appointments(
+        &caller,
+        &plan_id,
+        &vec![&env, (10, 0, 3_000, zero_hash)],
+    );
+    assert_eq!(ids.len(), 1);
+}
+
+#[test]
+fn post_discharge_scheduling_window_bounds_late_scheduling() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    client.complete_discharge(&caller, &plan_id, &2_500, &BytesN::from_array(&env, &[1; 32]));
+    client.set_allow_scheduling_post_dc(&caller, &true);
+    client.set_post_dc_scheduling_window(&caller, &100);
+
+    let zero_hash = BytesN::from_array(&env, &[0; 32]);
+
+    env.ledger().with_mut(|li| li.timestamp = 2_550);
+    let ids = client.schedule_followup_appointments(
+        &caller,
+        &plan_id,
+        &vec![&env, (10, 0, 3_000, zero_hash.clone())],
+    );
+    assert_eq!(ids.len(), 1);
+
+    env.ledger().with_mut(|li| li.timestamp = 2_601);
+    let blocked = client.try_schedule_followup_appointments(
+        &caller,
+        &plan_id,
+        &vec![&env, (10, 0, 3_000, zero_hash)],
+    );
+    assert_eq!(blocked, Err(Ok(crate::Error::AlreadyCompleted)));
+}
+
+#[test]
+fn plan_snapshot_is_immutable_after_later_plan_changes() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    client.assess_discharge_readiness(&caller, &plan_id, &vec![&env, 60, 60, 60, 60]);
+
+    let snapshot_id = client.create_plan_snapshot(&caller, &plan_id);
+    let snapshot = client.get_plan_snapshot(&snapshot_id).unwrap();
+    assert_eq!(snapshot.discharge_plan_id, plan_id);
+    assert_eq!(snapshot.export.readiness_history.len(), 1);
+
+    client.assess_discharge_readiness(&caller, &plan_id, &vec![&env, 95, 95, 95, 95]);
+
+    let unchanged = client.get_plan_snapshot(&snapshot_id).unwrap();
+    assert_eq!(unchanged.export.readiness_history.len(), 1);
+    assert_eq!(unchanged.export.readiness_history.get(0).unwrap().total, 60);
+}
+
+#[test]
+fn completing_with_a_cid_makes_it_readable_back() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    let cid = Bytes::from_slice(&env, b"bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi");
+
+    client.complete_discharge_with_cid(
+        &caller,
+        &plan_id,
+        &2_500,
+        &BytesN::from_array(&env, &[1; 32]),
+        &cid,
+    );
+
+    assert_eq!(client.get_completion_cid(&plan_id), cid);
+}
+
+#[test]
+fn verify_summary_hash_matches_the_anchored_completion_hash() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+
+    let not_yet = client.try_verify_summary_hash(&plan_id, &BytesN::from_array(&env, &[1; 32]));
+    assert_eq!(not_yet, Err(Ok(crate::Error::NotCompleted)));
+
+    let hash = BytesN::from_array(&env, &[1; 32]);
+    client.complete_discharge(&caller, &plan_id, &2_500, &hash);
+
+    assert!(client.verify_summary_hash(&plan_id, &hash));
+    assert!(!client.verify_summary_hash(&plan_id, &BytesN::from_array(&env, &[2; 32])));
+}
+
+#[test]
+fn get_destination_label_maps_each_code_and_rejects_unknown() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _caller) = setup(&env);
+
+    assert_eq!(client.get_destination_label(&0), symbol_short!("home"));
+    assert_eq!(client.get_destination_label(&1), symbol_short!("snf"));
+    assert_eq!(client.get_destination_label(&2), symbol_short!("rehab"));
+    assert_eq!(client.get_destination_label(&3), symbol_short!("other"));
+    assert_eq!(
+        client.try_get_destination_label(&4),
+        Err(Ok(crate::Error::InvalidInput))
+    );
+}
+
+#[test]
+fn get_order_type_label_maps_each_code_and_rejects_unknown() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _caller) = setup(&env);
+
+    assert_eq!(client.get_order_type_label(&0), symbol_short!("generic"));
+    assert_eq!(client.get_order_type_label(&10), symbol_short!("medic"));
+    assert_eq!(client.get_order_type_label(&20), symbol_short!("dme"));
+    assert_eq!(
+        client.get_order_type_label(&30),
+        symbol_short!("homehlth")
+    );
+    assert_eq!(
+        client.try_get_order_type_label(&99),
+        Err(Ok(crate::Error::InvalidInput))
+    );
+}
+
+#[test]
+fn get_specialty_label_maps_each_code_and_rejects_unknown() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _caller) = setup(&env);
+
+    assert_eq!(client.get_specialty_label(&0), symbol_short!("pcp"));
+    assert_eq!(client.get_specialty_label(&1), symbol_short!("cards"));
+    assert_eq!(client.get_specialty_label(&2), symbol_short!("surgery"));
+    assert_eq!(client.get_specialty_label(&3), symbol_short!("other"));
+    assert_eq!(
+        client.try_get_specialty_label(&4),
+        Err(Ok(crate::Error::InvalidInput))
+    );
+}
+
+#[test]
+fn threshold_at_completion_is_frozen_against_later_global_threshold_changes() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    client.set_global_readiness_threshold(&caller, &70, &false);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    client.complete_discharge_with_cid(
+        &caller,
+        &plan_id,
+        &2_500,
+        &BytesN::from_array(&env, &[1; 32]),
+        &Bytes::from_slice(&env, b"cid"),
+    );
+
+    assert_eq!(client.get_threshold_at_completion(&plan_id), 70);
+
+    client.set_global_readiness_threshold(&caller, &95, &false);
+
+    assert_eq!(client.get_threshold_at_completion(&plan_id), 70);
+    assert_eq!(
+        client.get_completion_details(&plan_id).threshold_at_completion,
+        70
+    );
+}
+
+#[test]
+fn carry_forward_to_new_plan_copies_open_barriers_and_future_appointments() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let source_id = client.initiate_discharge_planning(&caller, &7, &3, &1_000, &2_000, &1);
+    client.add_discharge_barrier(&caller, &source_id, &0);
+    client.add_discharge_barrier(&caller, &source_id, &1);
+    client.resolve_discharge_barrier(&caller, &source_id, &1);
+
+    client.schedule_followup_appointments(
+        &caller,
+        &source_id,
+        &vec![
+            &env,
+            (5, 0, 10_000, BytesN::from_array(&env, &[0; 32])),
+            (5, 0, 0, BytesN::from_array(&env, &[0; 32])),
+        ],
+    );
+
+    let new_id = client.carry_forward_to_new_plan(&caller, &source_id, &5_000, &6_000);
+    assert_ne!(new_id, source_id);
+
+    let new_plan = client.get_discharge_plan(&new_id);
+    assert_eq!(new_plan.patient_id, 7);
+    assert_eq!(new_plan.attending_provider_id, 3);
+    assert_eq!(new_plan.destination, 1);
+    assert_eq!(new_plan.admission_date, 5_000);
+    assert_eq!(new_plan.expected_discharge_date, 6_000);
+
+    let open = client.get_open_barriers(&new_id);
+    assert_eq!(open.len(), 1);
+    assert_eq!(open.get(0).unwrap().barrier_code, 0);
+
+    let carried_appointments = client.get_followup_appointments(&new_id);
+    assert_eq!(carried_appointments.len(), 1);
+    assert_eq!(carried_appointments.get(0).unwrap().time, 10_000);
+}
+
+#[test]
+fn discharge_quality_score_is_high_for_a_complete_plan_and_low_for_a_sparse_one() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let complete_plan = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    client.assess_discharge_readiness(&caller, &complete_plan, &vec![&env, 100, 100, 100, 100]);
+    client.create_discharge_orders(&caller, &complete_plan, &0, &BytesN::from_array(&env, &[1; 32]));
+    client.provide_discharge_education(&caller, &complete_plan, &0, &true, &0, &false);
+    client.schedule_followup_appointments(
+        &caller,
+        &complete_plan,
+        &vec![&env, (10, 0, 3_000, BytesN::from_array(&env, &[0; 32]))],
+    );
+
+    let sparse_plan = client.initiate_discharge_planning(&caller, &2, &1, &1_000, &2_000, &0);
+
+    assert_eq!(client.get_discharge_quality_score(&complete_plan), 100);
+    assert_eq!(client.get_discharge_quality_score(&sparse_plan), 0);
+}
+
+#[test]
+fn subscribed_contract_is_notified_on_a_readiness_event() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let subscriber_id = env.register_contract(None, MockSubscriber);
+    let subscriber_client = MockSubscriberClient::new(&env, &subscriber_id);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    client.subscribe_plan_events(&caller, &plan_id, &subscriber_id);
+
+    client.assess_discharge_readiness(&caller, &plan_id, &vec![&env, 80, 80, 80, 80]);
+    assert_eq!(subscriber_client.notify_count(), 1);
+
+    client.unsubscribe_plan_events(&caller, &plan_id, &subscriber_id);
+    client.assess_discharge_readiness(&caller, &plan_id, &vec![&env, 90, 90, 90, 90]);
+    assert_eq!(subscriber_client.notify_count(), 1);
+}
+
+#[test]
+fn can_complete_discharge_flips_as_each_gate_is_satisfied() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    assert!(!client.can_complete_discharge(&plan_id, &2_000));
+
+    client.assess_discharge_readiness(&caller, &plan_id, &vec![&env, 90, 90, 90, 90]);
+    assert!(client.can_complete_discharge(&plan_id, &2_000));
+
+    client.set_required_education_topics(&caller, &plan_id, &vec![&env, 0, 1]);
+    assert!(!client.can_complete_discharge(&plan_id, &2_000));
+    client.provide_discharge_education(&caller, &plan_id, &0, &true, &0, &false);
+    client.provide_discharge_education(&caller, &plan_id, &1, &true, &0, &false);
+    assert!(client.can_complete_discharge(&plan_id, &2_000));
+
+    client.set_require_patient_consent(&caller, &plan_id, &true);
+    assert!(!client.can_complete_discharge(&plan_id, &2_000));
+    client.record_patient_consent(&caller, &plan_id, &BytesN::from_array(&env, &[1; 32]), &true);
+    assert!(client.can_complete_discharge(&plan_id, &2_000));
+
+    client.set_require_barriers_resolved(&caller, &plan_id, &true);
+    client.add_discharge_barrier(&caller, &plan_id, &0);
+    assert!(!client.can_complete_discharge(&plan_id, &2_000));
+    client.resolve_discharge_barrier(&caller, &plan_id, &0);
+    assert!(client.can_complete_discharge(&plan_id, &2_000));
+
+    let order_id = client.create_discharge_orders(
+        &caller,
+        &plan_id,
+        &crate::ORDER_TYPE_DME,
+        &BytesN::from_array(&env, &[2; 32]),
+    );
+    assert!(!client.can_complete_discharge(&plan_id, &2_000));
+    client.finalize_open_orders(&caller, &plan_id, &1);
+    assert!(client.can_complete_discharge(&plan_id, &2_000));
+    let _ = order_id;
+
+    let far_future = 2_000 + crate::READINESS_FRESHNESS_WINDOW_SECS + 1;
+    assert!(!client.can_complete_discharge(&plan_id, &far_future));
+}
+
+#[test]
+fn get_discharge_blockers_reports_every_failing_gate() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    client.set_require_patient_consent(&caller, &plan_id, &true);
+
+    let blockers = client.get_discharge_blockers(&plan_id, &2_000);
+    assert_eq!(blockers, vec![&env, 1, 3]);
+
+    client.assess_discharge_readiness(&caller, &plan_id, &vec![&env, 90, 90, 90, 90]);
+    client.record_patient_consent(&caller, &plan_id, &BytesN::from_array(&env, &[1; 32]), &true);
+    assert_eq!(client.get_discharge_blockers(&plan_id, &2_000), vec![&env]);
+}
+
+#[test]
+fn pcp_followup_gate_requires_a_primary_care_appointment_within_the_window() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    client.assess_discharge_readiness(&caller, &plan_id, &vec![&env, 90, 90, 90, 90]);
+    client.set_require_pcp_followup(&caller, &plan_id, &true);
+    client.set_pcp_followup_window_days(&caller, &14);
+
+    assert!(!client.can_complete_discharge(&plan_id, &2_000));
+    assert_eq!(client.get_discharge_blockers(&plan_id, &2_000), vec![&env, 6]);
+
+    let zero_hash = BytesN::from_array(&env, &[0; 32]);
+    let within_window = 2_000 + 10 * 86_400;
+    client.schedule_followup_appointments(
+        &caller,
+        &plan_id,
+        &vec![&env, (5, 0, within_window, zero_hash.clone())],
+    );
+    assert!(client.can_complete_discharge(&plan_id, &2_000));
+
+    let plan_id_2 = client.initiate_discharge_planning(&caller, &2, &1, &1_000, &2_000, &0);
+    client.assess_discharge_readiness(&caller, &plan_id_2, &vec![&env, 90, 90, 90, 90]);
+    client.set_require_pcp_followup(&caller, &plan_id_2, &true);
+    client.set_pcp_followup_window_days(&caller, &14);
+
+    let outside_window = 2_000 + 20 * 86_400;
+    client.schedule_followup_appointments(
+        &caller,
+        &plan_id_2,
+        &vec![&env, (5, 0, outside_window, zero_hash)],
+    );
+    assert!(!client.can_complete_discharge(&plan_id_2, &2_000));
+    assert_eq!(client.get_discharge_blockers(&plan_id_2, &2_000), vec![&env, 6]);
+}
+
+#[test]
+fn reassign_appointments_provider_moves_every_matching_appointment() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    let zero_hash = BytesN::from_array(&env, &[0; 32]);
+    client.schedule_followup_appointments(
+        &caller,
+        &plan_id,
+        &vec![
+            &env,
+            (10, 0, 3_000, zero_hash.clone()),
+            (10, 1, 3_100, zero_hash.clone()),
+            (11, 0, 3_200, zero_hash),
+        ],
+    );
+
+    let changed = client.reassign_appointments_provider(&caller, &plan_id, &10, &99);
+    assert_eq!(changed, 2);
+
+    let appointments = client.get_followup_appointments(&plan_id);
+    assert_eq!(appointments.get(0).unwrap().provider_id, 99);
+    assert_eq!(appointments.get(1).unwrap().provider_id, 99);
+    assert_eq!(appointments.get(2).unwrap().provider_id, 11);
+}
+
+#[test]
+fn initiate_discharge_planning_rejects_a_zero_patient_id() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let result = client.try_initiate_discharge_planning(&caller, &0, &1, &1_000, &2_000, &0);
+    assert_eq!(result, Err(Ok(crate::Error::InvalidInput)));
+
+    let plan_id = client.initiate_discharge_planning(&caller, &7, &1, &1_000, &2_000, &0);
+    assert_eq!(plan_id, 1);
+}
+
+#[test]
+fn get_readiness_velocity_reflects_rising_and_falling_trajectories() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let rising_plan = client.initiate_discharge_planning(&caller, &1, &1, &0, &10_000, &0);
+    let result = client.try_get_readiness_velocity(&rising_plan);
+    assert_eq!(result, Err(Ok(crate::Error::InsufficientHistory)));
+
+    env.ledger().with_mut(|li| li.timestamp = 0);
+    client.assess_discharge_readiness(&caller, &rising_plan, &vec![&env, 40, 40, 40, 40]);
+    env.ledger().with_mut(|li| li.timestamp = 2 * 86_400);
+    client.assess_discharge_readiness(&caller, &rising_plan, &vec![&env, 80, 80, 80, 80]);
+    assert_eq!(client.get_readiness_velocity(&rising_plan), 20);
+
+    let falling_plan = client.initiate_discharge_planning(&caller, &2, &1, &0, &10_000, &0);
+    env.ledger().with_mut(|li| li.timestamp = 0);
+    client.assess_discharge_readiness(&caller, &falling_plan, &vec![&env, 80, 80, 80, 80]);
+    env.ledger().with_mut(|li| li.timestamp = 4 * 86_400);
+    client.assess_discharge_readiness(&caller, &falling_plan, &vec![&env, 40, 40, 40, 40]);
+    assert_eq!(client.get_readiness_velocity(&falling_plan), -10);
+}
+
+#[test]
+fn complete_discharge_is_blocked_until_mandatory_orders_exist() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    client.set_mandatory_orders(
+        &caller,
+        &crate::DESTINATION_HOME,
+        &vec![&env, crate::ORDER_TYPE_HOME_HEALTH],
+    );
+
+    let plan_id = client.initiate_discharge_planning(
+        &caller,
+        &1,
+        &1,
+        &1_000,
+        &2_000,
+        &crate::DESTINATION_HOME,
+    );
+
+    let blocked = client.try_complete_discharge(
+        &caller,
+        &plan_id,
+        &2_500,
+        &BytesN::from_array(&env, &[1; 32]),
+    );
+    assert_eq!(blocked, Err(Ok(crate::Error::MandatoryOrdersMissing)));
+
+    client.create_discharge_orders(
+        &caller,
+        &plan_id,
+        &crate::ORDER_TYPE_HOME_HEALTH,
+        &BytesN::from_array(&env, &[2; 32]),
+    );
+    client.complete_discharge(&caller, &plan_id, &2_500, &BytesN::from_array(&env, &[1; 32]));
+}
+
+#[test]
+fn physician_override_enables_completion_of_an_otherwise_not_ready_plan() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+    let physician = Address::generate(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    client.assess_discharge_readiness(&caller, &plan_id, &vec![&env, 10, 10, 10, 10]);
+    assert!(!client.can_complete_discharge(&plan_id, &2_000));
+
+    client.register_physician(&caller, &physician);
+    client.override_readiness(&physician, &plan_id, &true, &42);
+    assert!(client.can_complete_discharge(&plan_id, &2_000));
+
+    client.complete_discharge(&caller, &plan_id, &2_000, &BytesN::from_array(&env, &[1; 32]));
+}
+
+#[test]
+fn override_readiness_rejects_a_caller_who_is_not_a_registered_physician() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+    let impostor = Address::generate(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+
+    let result = client.try_override_readiness(&impostor, &plan_id, &true, &42);
+    assert_eq!(result, Err(Ok(crate::Error::NotAuthorized)));
+}
+
+#[test]
+fn register_agencies_batch_registers_every_id_in_the_batch() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let agencies = vec![
+        &env,
+        BytesN::from_array(&env, &[1; 32]),
+        BytesN::from_array(&env, &[2; 32]),
+        BytesN::from_array(&env, &[3; 32]),
+    ];
+    client.register_agencies_batch(&caller, &agencies);
+
+    for agency in agencies.iter() {
+        assert!(client.is_agency_registered(&agency));
+    }
+    assert!(!client.is_agency_registered(&BytesN::from_array(&env, &[9; 32])));
+}
+
+#[test]
+fn register_suppliers_batch_rejects_a_batch_over_the_cap() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let mut too_many = Vec::new(&env);
+    for i in 0..(crate::MAX_BATCH_REGISTRATION + 1) {
+        let mut bytes = [0u8; 32];
+        bytes[0] = (i % 256) as u8;
+        bytes[1] = (i / 256) as u8;
+        too_many.push_back(BytesN::from_array(&env, &bytes));
+    }
+
+    let result = client.try_register_suppliers_batch(&caller, &too_many);
+    assert_eq!(result, Err(Ok(crate::Error::TooManyOccurrences)));
+}
+
+#[test]
+fn get_plans_created_between_only_returns_plans_in_the_window() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let early = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+
+    env.ledger().with_mut(|li| li.timestamp = 5_000);
+    let in_window = client.initiate_discharge_planning(&caller, &2, &1, &1_000, &2_000, &0);
+
+    env.ledger().with_mut(|li| li.timestamp = 9_000);
+    let late = client.initiate_discharge_planning(&caller, &3, &1, &1_000, &2_000, &0);
+
+    let ids = client.get_plans_created_between(&3_000, &7_000, &0, &10);
+    assert_eq!(ids, vec![&env, in_window]);
+    let _ = (early, late);
+
+    let bad_range = client.try_get_plans_created_between(&7_000, &3_000, &0, &10);
+    assert_eq!(bad_range, Err(Ok(crate::Error::InvalidInput)));
+}
+
+#[test]
+fn assessing_readiness_after_completion_is_rejected_without_an_explicit_reopen() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    client.assess_discharge_readiness(&caller, &plan_id, &vec![&env, 90, 90, 90, 90]);
+    client.complete_discharge(&caller, &plan_id, &2_000, &BytesN::from_array(&env, &[1; 32]));
+
+    let blocked = client.try_assess_discharge_readiness(&caller, &plan_id, &vec![&env, 90, 90, 90, 90]);
+    assert_eq!(blocked, Err(Ok(crate::Error::InvalidStateTransition)));
+
+    client.reopen_discharge_plan(&caller, &plan_id);
+    client.assess_discharge_readiness(&caller, &plan_id, &vec![&env, 95, 95, 95, 95]);
+}
+
+#[test]
+fn get_estimated_dme_cost_sums_registered_costs_across_orders() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    client.register_dme_cost(&caller, &21, &15_000);
+    client.register_dme_cost(&caller, &22, &4_000);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    let wheelchair = client.create_discharge_orders(
+        &caller,
+        &plan_id,
+        &21,
+        &BytesN::from_array(&env, &[1; 32]),
+    );
+    client.create_discharge_orders(&caller, &plan_id, &22, &BytesN::from_array(&env, &[2; 32]));
+
+    assert_eq!(client.get_estimated_dme_cost(&plan_id), 19_000);
+    let _ = wheelchair;
+}
+
+#[test]
+fn patient_read_own_plan_scopes_access_to_the_registered_patient() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+    let patient = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let proof = BytesN::from_array(&env, &[7; 32]);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    client.register_patient_access(&caller, &1, &patient, &proof);
+
+    let summary = client.patient_read_own_plan(&patient, &plan_id, &proof);
+    assert_eq!(summary.discharge_plan_id, plan_id);
+    assert_eq!(summary.destination, 0);
+
+    let wrong_proof = BytesN::from_array(&env, &[9; 32]);
+    let rejected = client.try_patient_read_own_plan(&patient, &plan_id, &wrong_proof);
+    assert_eq!(rejected, Err(Ok(crate::Error::NotAuthorized)));
+
+    let rejected_stranger = client.try_patient_read_own_plan(&stranger, &plan_id, &proof);
+    assert_eq!(rejected_stranger, Err(Ok(crate::Error::NotAuthorized)));
+}
+
+#[test]
+fn provide_discharge_education_records_whether_an_interpreter_was_used() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    client.set_patient_language(&caller, &plan_id, &9);
+    client.provide_discharge_education(&caller, &plan_id, &0, &true, &2, &true);
+
+    let records = client.get_education_records(&plan_id);
+    assert!(records.get(0).unwrap().interpreter_used);
+    assert!(!records.get(0).unwrap().language_matched);
+}
+
+#[test]
+fn cancel_dme_order_records_restock_and_rejects_once_delivered() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    let order_id = client.create_discharge_orders(
+        &caller,
+        &plan_id,
+        &crate::ORDER_TYPE_DME,
+        &BytesN::from_array(&env, &[1; 32]),
+    );
+
+    client.cancel_dme_order(&caller, &plan_id, &order_id, &true);
+    let order = client
+        .get_discharge_orders(&plan_id)
+        .iter()
+        .find(|o| o.id == order_id)
+        .unwrap();
+    assert!(order.cancelled);
+    assert!(order.restock);
+
+    let delivered_order_id = client.create_discharge_orders(
+        &caller,
+        &plan_id,
+        &crate::ORDER_TYPE_DME,
+        &BytesN::from_array(&env, &[2; 32]),
+    );
+    client.finalize_open_orders(&caller, &plan_id, &1);
+    let result = client.try_cancel_dme_order(&caller, &plan_id, &delivered_order_id, &false);
+    assert_eq!(result, Err(Ok(crate::Error::AlreadyDelivered)));
+}
+
+#[test]
+fn get_last_updated_advances_after_an_order_is_created() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &500, &2_000, &0);
+    let initial = client.get_last_updated(&plan_id);
+    assert_eq!(initial, 1_000);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_500);
+    client.create_discharge_orders(&caller, &plan_id, &0, &BytesN::from_array(&env, &[1; 32]));
+
+    assert_eq!(client.get_last_updated(&plan_id), 1_500);
+}
+
+#[test]
+fn get_plan_statuses_reports_each_plans_stage_and_skips_unknown_ids() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let initiated = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    let assessed = client.initiate_discharge_planning(&caller, &2, &1, &1_000, &2_000, &0);
+    client.assess_discharge_readiness(&caller, &assessed, &vec![&env, 80, 80, 80, 80]);
+    let ordered = client.initiate_discharge_planning(&caller, &3, &1, &1_000, &2_000, &0);
+    client.create_discharge_orders(&caller, &ordered, &0, &BytesN::from_array(&env, &[1; 32]));
+    let completed = client.initiate_discharge_planning(&caller, &4, &1, &1_000, &2_000, &0);
+    client.assess_discharge_readiness(&caller, &completed, &vec![&env, 90, 90, 90, 90]);
+    client.complete_discharge(&caller, &completed, &2_000, &BytesN::from_array(&env, &[2; 32]));
+
+    let statuses = client.get_plan_statuses(&vec![&env, initiated, assessed, ordered, completed, 999]);
+    assert_eq!(
+        statuses,
+        vec![
+            &env,
+            (initiated, crate::STAGE_INITIATED),
+            (assessed, crate::STAGE_ASSESSED),
+            (ordered, crate::STAGE_ORDERS_PLACED),
+            (completed, crate::STAGE_COMPLETED),
+        ]
+    );
+}
+
+#[test]
+fn get_home_health_fulfillment_pct_caps_at_100() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    client.arrange_home_health(&caller, &plan_id, &4, &2);
+    for _ in 0..6 {
+        client.record_home_health_visit(&caller, &plan_id);
+    }
+    assert_eq!(client.get_home_health_fulfillment_pct(&plan_id), 75);
+
+    for _ in 0..10 {
+        client.record_home_health_visit(&caller, &plan_id);
+    }
+    assert_eq!(client.get_home_health_fulfillment_pct(&plan_id), 100);
+}
+
+#[test]
+fn get_override_log_captures_a_readiness_override_and_an_expedited_completion() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+    let physician = Address::generate(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    client.register_physician(&caller, &physician);
+    client.override_readiness(&physician, &plan_id, &true, &1);
+    client.complete_discharge_expedited(
+        &caller,
+        &plan_id,
+        &2_000,
+        &BytesN::from_array(&env, &[1; 32]),
+        &2,
+    );
+
+    let log = client.get_override_log(&plan_id);
+    assert_eq!(log.len(), 2);
+    assert_eq!(log.get(0).unwrap().0, crate::OVERRIDE_TYPE_READINESS);
+    assert_eq!(log.get(0).unwrap().1, physician);
+    assert_eq!(log.get(1).unwrap().0, crate::OVERRIDE_TYPE_EXPEDITED_COMPLETION);
+    assert_eq!(log.get(1).unwrap().1, caller);
+}
+
+#[test]
+fn get_appointment_conflicts_finds_close_appointments() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    let zero_hash = BytesN::from_array(&env, &[0; 32]);
+    let ids = client.schedule_followup_appointments(
+        &caller,
+        &plan_id,
+        &vec![
+            &env,
+            (10, 0, 3_000, zero_hash.clone()),
+            (11, 1, 3_200, zero_hash.clone()),
+            (12, 2, 10_000, zero_hash),
+        ],
+    );
+
+    let conflicts = client.get_appointment_conflicts(&plan_id, &300);
+    assert_eq!(conflicts, vec![&env, (ids.get(0).unwrap(), ids.get(1).unwrap())]);
+}
+
+#[test]
+fn compute_lace_index_matches_the_documented_scoring_table() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let nine_days = 9 * 86_400;
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &0, &nine_days, &0);
+
+    // L=5 (7-13 days), A=3 (capped), C=5 (capped), E=4 (capped) => 17
+    let score = client.compute_lace_index(&caller, &plan_id, &9, &8, &6);
+    assert_eq!(score, 17);
+    assert_eq!(client.get_discharge_plan(&plan_id).lace_index, Some(17));
+
+    let short_stay = client.initiate_discharge_planning(&caller, &2, &1, &0, &0, &0);
+    assert_eq!(client.compute_lace_index(&caller, &short_stay, &0, &0, &0), 0);
+}
+
+#[test]
+fn compute_readmission_risk_defaults_to_equal_weights_until_tuned() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    // Bits 0 and 2 set: equal default weights (25 each) give 50/100.
+    let default_score = client.compute_readmission_risk(&0b0101);
+    assert_eq!(default_score, 50);
+
+    client.set_risk_factor_weights(&caller, &vec![&env, 10, 10, 70, 10]);
+    let tuned_score = client.compute_readmission_risk(&0b0101);
+    assert_eq!(tuned_score, 80);
+
+    // All four factors present always caps at 100, whatever the weights.
+    assert_eq!(client.compute_readmission_risk(&0b1111), 100);
+}
+
+#[test]
+fn get_orders_by_actor_filters_out_other_callers_orders() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+    let other_caller = Address::generate(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &0, &1_000, &0);
+    let hash = BytesN::from_array(&env, &[7u8; 32]);
+
+    client.create_dc_orders_with_scheme(&caller, &plan_id, &10, &hash, &0);
+    client.create_dc_orders_with_scheme(&other_caller, &plan_id, &20, &hash, &0);
+    client.create_dc_orders_with_scheme(&caller, &plan_id, &30, &hash, &0);
+
+    let callers_orders = client.get_orders_by_actor(&plan_id, &caller);
+    assert_eq!(callers_orders.len(), 2);
+    for order in callers_orders.iter() {
+        assert_eq!(order.acted_by, caller);
+    }
+
+    let other_orders = client.get_orders_by_actor(&plan_id, &other_caller);
+    assert_eq!(other_orders.len(), 1);
+}
+
+#[test]
+fn get_readiness_confidence_requires_at_least_two_assessments() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &0, &1_000, &0);
+    client.assess_discharge_readiness(&caller, &plan_id, &vec![&env, 50, 50, 50, 50]);
+
+    let result = client.try_get_readiness_confidence(&plan_id);
+    assert_eq!(result, Err(Ok(crate::Error::InsufficientHistory)));
+}
+
+#[test]
+fn get_readiness_confidence_is_higher_for_a_stable_trajectory_than_a_volatile_one() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let stable_plan = client.initiate_discharge_planning(&caller, &1, &1, &0, &1_000, &0);
+    client.assess_discharge_readiness(&caller, &stable_plan, &vec![&env, 60, 60, 60, 60]);
+    client.assess_discharge_readiness(&caller, &stable_plan, &vec![&env, 62, 62, 62, 62]);
+    client.assess_discharge_readiness(&caller, &stable_plan, &vec![&env, 64, 64, 64, 64]);
+    let stable_confidence = client.get_readiness_confidence(&stable_plan);
+
+    let volatile_plan = client.initiate_discharge_planning(&caller, &2, &1, &0, &1_000, &0);
+    client.assess_discharge_readiness(&caller, &volatile_plan, &vec![&env, 20, 20, 20, 20]);
+    client.assess_discharge_readiness(&caller, &volatile_plan, &vec![&env, 80, 80, 80, 80]);
+    client.assess_discharge_readiness(&caller, &volatile_plan, &vec![&env, 10, 10, 10, 10]);
+    let volatile_confidence = client.get_readiness_confidence(&volatile_plan);
+
+    assert!(stable_confidence > volatile_confidence);
+}
+
+#[test]
+fn get_counters_reflects_plans_and_appointments_created() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let zero_hash = BytesN::from_array(&env, &[0u8; 32]);
+    let plan_a = client.initiate_discharge_planning(&caller, &1, &1, &0, &1_000, &0);
+    let _plan_b = client.initiate_discharge_planning(&caller, &2, &1, &0, &1_000, &0);
+
+    client.schedule_followup_appointments(
+        &caller,
+        &plan_a,
+        &vec![
+            &env,
+            (10, 0, 3_000, zero_hash.clone()),
+            (11, 1, 3_200, zero_hash.clone()),
+            (12, 2, 10_000, zero_hash),
+        ],
+    );
+
+    assert_eq!(client.get_counters(), (2, 3));
+}
+
+#[test]
+fn set_diagnosis_code_is_reflected_on_the_plan_and_the_diagnosis_index() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    client.set_diagnosis_code(&caller, &plan_id, &250);
+
+    assert_eq!(client.get_discharge_plan(&plan_id).diagnosis_code, Some(250));
+
+    let matching = client.get_plans_by_diagnosis(&250, &0, &10);
+    assert_eq!(matching.len(), 1);
+    assert_eq!(matching.get(0).unwrap(), plan_id);
+}
+
+#[test]
+fn set_diagnosis_code_is_rejected_once_the_plan_has_completed() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, caller) = setup(&env);
+
+    let plan_id = client.initiate_discharge_planning(&caller, &1, &1, &1_000, &2_000, &0);
+    let hash = BytesN::from_array(&env, &[9u8; 32]);
+    client.complete_discharge_expedited(&caller, &plan_id, &2_000, &hash, &0);
+
+    let result = client.try_set_diagnosis_code(&caller, &plan_id, &250);
+    assert_eq!(result, Err(Ok(crate::Error::AlreadyCompleted)));
+}
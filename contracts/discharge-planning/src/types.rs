@@ -0,0 +1,486 @@
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, Env, Vec};
+
+/// 0=Home, 1=SNF, 2=Rehab, 3=Other. Kept as a plain `u32` rather than a
+/// Soroban enum so new destinations can be added without a client upgrade.
+pub type Destination = u32;
+
+pub const DESTINATION_HOME: u32 = 0;
+pub const DESTINATION_SNF: u32 = 1;
+pub const DESTINATION_REHAB: u32 = 2;
+pub const DESTINATION_OTHER: u32 = 3;
+
+/// Lifecycle stage of a `DischargePlan`, in the order a plan normally
+/// progresses through them.
+pub const STAGE_INITIATED: u32 = 0;
+pub const STAGE_ASSESSED: u32 = 1;
+pub const STAGE_ORDERS_PLACED: u32 = 2;
+pub const STAGE_COMPLETED: u32 = 3;
+
+/// Readiness total needed for `is_ready` when no per-plan, per-destination
+/// or contract-wide override applies (see `effective_readiness_threshold`).
+pub const DEFAULT_READINESS_THRESHOLD: u32 = 75;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DischargePlan {
+    pub id: u64,
+    pub patient_id: u64,
+    pub attending_provider_id: u64,
+    pub admission_date: u64,
+    pub expected_discharge_date: u64,
+    pub actual_discharge_date: u64,
+    pub destination: Destination,
+    pub status: u32,
+    pub created_at: u64,
+    pub language_code: u32,
+    pub escalated: bool,
+    pub readmitted: bool,
+    /// Diagnosis-related group code for billing, `None` until set via
+    /// `set_drg_code`.
+    pub drg_code: Option<u32>,
+    /// When set by `set_require_followup_before_dc`, `complete_discharge`
+    /// fails unless at least one follow-up appointment has been scheduled.
+    pub require_followup_before_dc: bool,
+    /// When set by `set_require_patient_consent`, `complete_discharge` fails
+    /// unless `record_patient_consent` has recorded `consented = true`.
+    pub require_patient_consent: bool,
+    /// When set by `set_require_barriers_resolved`, `complete_discharge`
+    /// fails with `Error::OpenBarriersRemain` while any discharge barrier
+    /// is still open.
+    pub require_barriers_resolved: bool,
+    /// Care-pathway template this plan follows (e.g. "hip replacement",
+    /// "CHF"), `None` until set via `set_care_pathway`.
+    pub pathway_id: Option<u32>,
+    /// Bumped by `storage::set_plan` on every write, for worklist "last
+    /// touched" sorting via `get_last_updated`.
+    pub last_updated: u64,
+    /// Most recently computed LACE readmission-risk score, `None` until
+    /// `compute_lace_index` is called.
+    pub lace_index: Option<u32>,
+    /// When set by `set_require_pcp_followup`, `can_complete_discharge`
+    /// fails unless a primary-care (`specialty == 0`) follow-up
+    /// appointment falls within the configured PCP follow-up window of
+    /// `expected_discharge_date`.
+    pub require_pcp_followup: bool,
+    /// When set by `set_require_authorization`, `arrange_home_health` and
+    /// `coordinate_with_snf` fail with `Error::AuthorizationRequired`
+    /// unless `record_authorization` has recorded the matching service
+    /// type as authorized.
+    pub require_authorization: bool,
+    /// Principal diagnosis (ICD code), set by `set_diagnosis_code` and
+    /// indexed for `get_plans_by_diagnosis`.
+    pub diagnosis_code: Option<u32>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReadinessAssessment {
+    pub sub_scores: Vec<u32>,
+    pub total: u32,
+    /// Authoritative only once `cosigned` is true when cosigning is
+    /// required (see `require_readiness_cosign`); `false` until then even
+    /// if `total` clears the threshold.
+    pub is_ready: bool,
+    pub assessed_at: u64,
+    pub assessor: Address,
+    /// `true` when no second sign-off is required, or once a distinct
+    /// clinician has called `cosign_readiness_assessment`.
+    pub cosigned: bool,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DischargeOrder {
+    pub id: u64,
+    pub order_type: u32,
+    pub details_hash: BytesN<32>,
+    pub created_at: u64,
+    pub acted_by: Address,
+    /// `None` while the order is still open; set only by
+    /// `finalize_open_orders`. `mark_order_fulfilled` and `cancel_dme_order`
+    /// record their outcome in `fulfilled_at` / `cancelled` and leave it as is.
+    pub status: Option<u32>,
+    /// Off-chain encryption scheme used for the document `details_hash`
+    /// references, so a future decryptor knows which scheme to apply.
+    pub scheme: u32,
+    /// Expected delivery date for equipment orders (see `ORDER_TYPE_DME`
+    /// and `modify_dme_order`); `0` for order types that don't schedule a
+    /// delivery.
+    pub scheduled_for: u64,
+    /// Set by `cancel_dme_order`; `restock` only has meaning when this is
+    /// `true`.
+    pub cancelled: bool,
+    /// Whether a cancelled DME order's equipment should be returned to
+    /// supplier stock, set by `cancel_dme_order`.
+    pub restock: bool,
+    /// Set by `mark_order_fulfilled`; `None` until the order is fulfilled.
+    pub fulfilled_at: Option<u64>,
+    /// `fulfilled_at - created_at`, set alongside `fulfilled_at` by
+    /// `mark_order_fulfilled` for `get_average_order_turnaround`.
+    pub turnaround_secs: Option<u64>,
+    /// DME supplier this order is addressed to, `0` if unset. Set by
+    /// `set_order_supplier`; used by `get_supplier_pending_deliveries`.
+    pub supplier_id: u64,
+    /// Off-chain rule this order's activation is conditioned on (e.g.
+    /// "insulin if glucose > 200"). Only meaningful when `is_conditional`
+    /// is set; zeroed otherwise. Set by `set_order_condition`.
+    pub condition_hash: BytesN<32>,
+    /// Whether `condition_hash` gates this order's activation.
+    pub is_conditional: bool,
+}
+
+/// `scheme` value used by `create_discharge_orders` when the caller doesn't
+/// specify one explicitly (see `create_dc_orders_with_scheme`).
+pub const DEFAULT_ENCRYPTION_SCHEME: u32 = 0;
+
+/// `order_type` used for medication orders, so `get_prior_meds_for_readmission`
+/// knows which orders to pull out of a plan's general order list.
+pub const ORDER_TYPE_MEDICATION: u32 = 10;
+
+/// `order_type` used for durable medical equipment orders (see
+/// `modify_dme_order`).
+pub const ORDER_TYPE_DME: u32 = 20;
+
+/// `order_type` used for home-health referral orders, commonly required by
+/// `set_mandatory_orders` for home discharges.
+pub const ORDER_TYPE_HOME_HEALTH: u32 = 30;
+
+/// `FollowUpAppointment::specialty` used for primary-care follow-ups,
+/// checked by the PCP follow-up gate (`set_require_pcp_followup`).
+pub const SPECIALTY_PRIMARY_CARE: u32 = 0;
+pub const SPECIALTY_CARDIOLOGY: u32 = 1;
+pub const SPECIALTY_SURGERY: u32 = 2;
+pub const SPECIALTY_OTHER: u32 = 3;
+
+/// One entry in a plan's append-only event log, used to cross-check
+/// stored state against what was actually emitted (see
+/// `verify_plan_integrity`).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventLogEntry {
+    pub code: u32,
+    pub timestamp: u64,
+}
+
+pub const EVENT_INITIATED: u32 = 0;
+pub const EVENT_READINESS_ASSESSED: u32 = 1;
+pub const EVENT_ORDER_CREATED: u32 = 2;
+pub const EVENT_COMPLETED: u32 = 3;
+
+pub type OrderList = Vec<DischargeOrder>;
+pub type EventLog = Vec<EventLogEntry>;
+
+/// One entry in a plan's append-only override log (`get_override_log`),
+/// covering every mechanism that lets a caller bypass a normal clinical
+/// rule: a readiness override, an expedited completion, or an emergency
+/// read.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OverrideLogEntry {
+    pub override_type: u32,
+    pub actor: Address,
+    pub timestamp: u64,
+}
+
+pub const OVERRIDE_TYPE_READINESS: u32 = 0;
+pub const OVERRIDE_TYPE_EXPEDITED_COMPLETION: u32 = 1;
+pub const OVERRIDE_TYPE_EMERGENCY_ACCESS: u32 = 2;
+
+pub type OverrideLog = Vec<OverrideLogEntry>;
+
+/// One entry in an order's append-only amendment log (`get_order_amendments`),
+/// preserving the hash an amendment replaced so a corrected document never
+/// erases the record of what it superseded.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OrderAmendmentEntry {
+    pub order_id: u64,
+    pub previous_hash: BytesN<32>,
+    pub new_hash: BytesN<32>,
+    pub amended_by: Address,
+    pub amended_at: u64,
+}
+
+pub type OrderAmendmentLog = Vec<OrderAmendmentEntry>;
+
+pub const SERVICE_TYPE_HOME_HEALTH: u32 = 0;
+pub const SERVICE_TYPE_SNF: u32 = 1;
+
+/// Payer authorization status for one service type on a plan, set by
+/// `record_authorization` and checked by `arrange_home_health` /
+/// `coordinate_with_snf` when `require_authorization` is set.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuthorizationRecord {
+    pub service_type: u32,
+    pub authorized: bool,
+    pub auth_ref_hash: BytesN<32>,
+    pub recorded_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EducationRecord {
+    pub topic: u32,
+    pub provided_at: u64,
+    pub completed: bool,
+    pub language_matched: bool,
+    pub interpreter_used: bool,
+}
+
+pub type EducationRecords = Vec<EducationRecord>;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FollowUpAppointment {
+    pub id: u64,
+    pub provider_id: u64,
+    pub specialty: u32,
+    pub time: u64,
+    /// Hash of the transfer-of-care summary handed to the receiving
+    /// provider, or the zero hash (see `is_zero_hash`) if none was attached
+    /// when the appointment was scheduled.
+    pub care_summary_hash: BytesN<32>,
+    /// Adherence status, set by `update_appointment_status`: `0`=Scheduled
+    /// (the default), `1`=Completed, `2`=NoShow, `3`=Cancelled.
+    pub status: u32,
+}
+
+pub type Appointments = Vec<FollowUpAppointment>;
+
+pub const APPOINTMENT_STATUS_SCHEDULED: u32 = 0;
+pub const APPOINTMENT_STATUS_COMPLETED: u32 = 1;
+pub const APPOINTMENT_STATUS_NO_SHOW: u32 = 2;
+pub const APPOINTMENT_STATUS_CANCELLED: u32 = 3;
+
+/// Upper bound on a single `schedule_recurring_followup` call, to keep a
+/// misconfigured recurrence from generating an unbounded appointment series.
+pub const MAX_RECURRING_OCCURRENCES: u32 = 52;
+
+/// Self-contained bundle used to move a plan between contract instances
+/// (see `export_plan_blob` / `import_plan_blob`).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlanExportBundle {
+    pub plan: DischargePlan,
+    pub readiness_history: Vec<ReadinessAssessment>,
+    pub orders: OrderList,
+}
+
+/// Per-sub-collection cap of a `FullPlanExport` (oldest entries kept), so the
+/// result stays within a reasonable transaction/XDR size regardless of
+/// history depth.
+pub const FULL_EXPORT_MAX_ITEMS: u32 = 50;
+
+/// Upper bound on a single `register_agencies_batch` /
+/// `register_suppliers_batch` call, so a misconfigured onboarding import
+/// can't pile an unbounded number of writes into one transaction.
+pub const MAX_BATCH_REGISTRATION: u32 = 50;
+
+/// Read-only single-fetch bundle of everything known about a plan, for
+/// offline-capable clients (see `get_full_plan_export`). Each sub-collection
+/// is capped at `FULL_EXPORT_MAX_ITEMS` entries (oldest-first).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FullPlanExport {
+    pub plan: DischargePlan,
+    pub readiness_history: Vec<ReadinessAssessment>,
+    pub orders: OrderList,
+    pub home_health: Option<HomeHealthArrangement>,
+    pub appointments: Appointments,
+    pub education_records: EducationRecords,
+    pub risk_history: Vec<RiskRecord>,
+}
+
+/// The patient-facing view of a plan returned by `patient_read_own_plan`,
+/// deliberately narrower than `FullPlanExport` (no clinician notes, no
+/// other patients' data reachable through provider ids).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DischargeSummary {
+    pub discharge_plan_id: u64,
+    pub status: u32,
+    pub destination: u32,
+    pub expected_discharge_date: u64,
+    pub actual_discharge_date: u64,
+    pub is_ready: bool,
+}
+
+/// A barrier to discharge (e.g. no ride home, no bed at SNF, pending labs),
+/// tracked via `add_discharge_barrier` / `resolve_discharge_barrier`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DischargeBarrier {
+    pub barrier_code: u32,
+    pub added_at: u64,
+    pub resolved: bool,
+}
+
+pub type DischargeBarriers = Vec<DischargeBarrier>;
+
+/// Patient consent record for a discharge plan, recorded by
+/// `record_patient_consent`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConsentRecord {
+    pub consent_hash: BytesN<32>,
+    pub consented: bool,
+    pub recorded_at: u64,
+}
+
+/// Skilled-nursing-facility transfer coordination for a plan, recorded by
+/// `coordinate_with_snf`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SnfCoordination {
+    pub facility_id: u64,
+    pub transfer_date: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HomeHealthArrangement {
+    pub frequency_per_week: u32,
+    pub duration_weeks: u32,
+    pub visits_completed: u32,
+}
+
+/// A readmission-risk score at or above this causes escalation.
+pub const ESCALATION_SCORE_THRESHOLD: u32 = 80;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RiskRecord {
+    pub score: u32,
+    pub recorded_at: u64,
+    /// Bitmask of the four defined readmission risk factors (e.g. bit 0 =
+    /// polypharmacy, bit 1 = prior admission, bit 2 = lives alone, bit 3 =
+    /// low health literacy) present when this score was recorded.
+    pub factors: u32,
+}
+
+/// Number of bit positions `get_risk_factor_prevalence` reports on.
+pub const RISK_FACTOR_COUNT: u32 = 4;
+
+/// Upper bound on a single `set_risk_factor_weights` entry. Keeps
+/// `compute_readmission_risk`'s `weighted_sum * 100` within `u32` range
+/// even when every one of the `RISK_FACTOR_COUNT` factors is set.
+pub const MAX_RISK_FACTOR_WEIGHT: u32 = 1_000_000;
+
+/// A physician's clinical-judgment override of the readiness gate, recorded
+/// by `override_readiness`. Takes precedence over the computed readiness
+/// total regardless of freshness.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReadinessOverride {
+    pub ready: bool,
+    pub justification_code: u32,
+    pub overridden_by: Address,
+    pub overridden_at: u64,
+}
+
+/// How long after `assessed_at` a "ready" readiness assessment still counts
+/// as fresh for `can_complete_discharge`'s readiness gate.
+pub const READINESS_FRESHNESS_WINDOW_SECS: u64 = 7 * 86_400;
+
+/// Upper bound on how many subscribers a single plan can register via
+/// `subscribe_plan_events`, so `notify_subscribers` can't be driven into an
+/// unbounded cross-call fan-out.
+pub const MAX_SUBSCRIBERS_PER_PLAN: u32 = 10;
+
+pub type Subscribers = Vec<Address>;
+
+/// 0=Spouse, 1=Child, 2=Parent, 3=Other.
+pub const MAX_RELATIONSHIP_CODE: u32 = 3;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Caregiver {
+    pub caregiver_id: BytesN<32>,
+    pub relationship: u32,
+}
+
+pub type Caregivers = Vec<Caregiver>;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CompletionDetails {
+    pub discharge_summary_hash: BytesN<32>,
+    pub completed_at: u64,
+    /// Hours between the last "ready" (`is_ready`) assessment and
+    /// `actual_discharge_date`, or `None` if no ready assessment exists.
+    /// A quality-process signal: a large value means the patient sat ready
+    /// for discharge far longer than necessary.
+    pub hours_ready_to_discharge: Option<u64>,
+    /// Off-chain IPFS CID of the discharge summary, alongside
+    /// `discharge_summary_hash`. Empty when the caller didn't supply one
+    /// (see `complete_discharge_with_cid`).
+    pub summary_cid: Bytes,
+    /// The readiness threshold (see `effective_readiness_threshold`) in
+    /// effect at the moment of completion, frozen here so later changes to
+    /// global/destination/per-plan thresholds can't retroactively alter an
+    /// audit trail.
+    pub threshold_at_completion: u32,
+}
+
+pub fn is_zero_hash(hash: &BytesN<32>) -> bool {
+    hash.to_array() == [0u8; 32]
+}
+
+/// In-progress per-domain readiness assessment. A plan's total isn't
+/// recomputed until every domain has been assessed by some clinician
+/// (see `assess_readiness_domain`).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DomainProgress {
+    pub scores: Vec<u32>,
+    pub has_assessor: Vec<bool>,
+}
+
+/// One vital-signs stability check recorded by `record_vitals_check`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VitalsCheck {
+    pub stable: bool,
+    pub checked_at: u64,
+}
+
+pub type VitalsChecks = Vec<VitalsCheck>;
+
+/// Number of readiness sub-score domains tracked per plan (medical
+/// stability, functional status, medications, and social support).
+pub const READINESS_DOMAIN_COUNT: u32 = 4;
+
+/// Sub-score index treated as "medical stability" for the auto-adjustment
+/// `record_vitals_check` applies on top of the latest readiness assessment.
+pub const MEDICAL_STABILITY_DOMAIN: usize = 0;
+
+/// Amount `record_vitals_check` shifts the medical-stability sub-score by,
+/// per check, clamped to `[0, 100]`.
+pub const VITALS_STABILITY_STEP: u32 = 15;
+
+/// Immutable point-in-time capture of a plan's full state, taken by
+/// `create_plan_snapshot` for audit purposes. Never updated once written.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlanSnapshot {
+    pub discharge_plan_id: u64,
+    pub taken_at: u64,
+    pub export: FullPlanExport,
+}
+
+impl DomainProgress {
+    pub fn empty(env: &Env) -> Self {
+        let mut scores = Vec::new(env);
+        let mut has_assessor = Vec::new(env);
+        for _ in 0..READINESS_DOMAIN_COUNT {
+            scores.push_back(0);
+            has_assessor.push_back(false);
+        }
+        Self { scores, has_assessor }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.has_assessor.iter().all(|assessed| assessed)
+    }
+}